@@ -0,0 +1,262 @@
+//! Provides the evolutionary algorithm, its population and clause-level crossover.
+
+use log::{trace,};
+use rand::prelude::{SliceRandom,};
+use rand::rngs::StdRng;
+use rand::{
+	Rng,
+	SeedableRng,
+};
+use rayon::prelude::*;
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::neighbourhood::{
+	draw_seeds,
+	Neighbourhood,
+};
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+use crate::boolean_formulae::bitset::Bitset;
+use crate::boolean_formulae::clause::Clause;
+use crate::boolean_formulae::dnf::DNF;
+use crate::boolean_formulae::evaluation::Cover;
+use crate::boolean_formulae::sample_store::SampleStore;
+
+/// Recombines and mutates a population of feasible states and keeps the best of parents
+/// and offspring in each iteration.
+#[derive(Debug, Clone)]
+pub struct Evolutionary {
+	/// How many states the population consists of, also the number of offspring per
+	/// generation.
+	population_size:       usize,
+	/// How many randomly chosen states compete for being selected as a parent.
+	tournament_size:       usize,
+	/// Probability of an offspring being created by crossover of two parents instead of
+	/// copying one parent, between 0 and 1.
+	crossover_probability: f64,
+	/// Probability of an offspring being mutated, between 0 and 1.
+	mutation_probability:  f64,
+	/// How many random feasible moves of the neighbourhood generators a mutation consists
+	/// of.
+	mutation_strength:     u32,
+	/// The population, created when the algorithm is initialized.
+	population:            Option<Population>,
+	/// How many generations were bred.
+	generation:            u32,
+}
+
+impl Evolutionary {
+	/// Creates an evolutionary algorithm with the given parameters.
+	#[must_use]
+	pub const fn new(
+		population_size: usize,
+		tournament_size: usize,
+		crossover_probability: f64,
+		mutation_probability: f64,
+		mutation_strength: u32,
+	) -> Self {
+		Self {
+			population_size,
+			tournament_size,
+			crossover_probability,
+			mutation_probability,
+			mutation_strength,
+			population: None,
+			generation: 0,
+		}
+	}
+}
+
+impl SearchAlgorithm for Evolutionary {
+	fn initialize(
+		&mut self,
+		initial_state: &State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) {
+		// Diversify the initial population by mutating copies of the initial state.
+		let mutation_strength = self.mutation_strength;
+		let initial_population = draw_seeds(rng, self.population_size.max(1))
+			.into_par_iter()
+			.enumerate()
+			.map(|(index, seed)| {
+				let mut state = initial_state.clone();
+				if index > 0 {
+					neighbourhood.mutate(&mut state, mutation_strength, &mut StdRng::seed_from_u64(seed));
+				}
+				state
+			})
+			.collect();
+		self.population = Some(Population::new(initial_population, regularizer));
+	}
+
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let population = self
+			.population
+			.as_mut()
+			.expect("The population is created when the algorithm is initialized.");
+		let (tournament_size, crossover_probability, mutation_probability, mutation_strength) = (
+			self.tournament_size,
+			self.crossover_probability,
+			self.mutation_probability,
+			self.mutation_strength,
+		);
+
+		let parents: &Population = population;
+		let offspring: Vec<State> = draw_seeds(rng, parents.len())
+			.into_par_iter()
+			.map(|seed| {
+				let mut rng = StdRng::seed_from_u64(seed);
+				let first_parent = parents.tournament(tournament_size, &mut rng);
+				let mut child = if rng.gen_bool(crossover_probability) {
+					let second_parent = parents.tournament(tournament_size, &mut rng);
+					crossover(
+						first_parent,
+						second_parent,
+						&mut rng,
+						neighbourhood.positive_samples(),
+						neighbourhood.negative_samples(),
+					)
+				} else {
+					first_parent.clone()
+				};
+				if rng.gen_bool(mutation_probability) {
+					neighbourhood.mutate(&mut child, mutation_strength, &mut rng);
+				}
+				child
+			})
+			.collect();
+
+		population.select(offspring, regularizer);
+		trace!(
+			"Best state of generation {} has value {}.",
+			self.generation,
+			regularizer.regularize(population.best())
+		);
+		self.generation += 1;
+		*state = population.best().clone();
+		true
+	}
+}
+
+/// A population of feasible states, ordered by their regularizer value.
+#[derive(Debug, Clone)]
+pub struct Population {
+	/// The states together with their regularizer value, best first.
+	individuals: Vec<(f64, State)>,
+}
+
+impl Population {
+	/// Creates a population of the given states.
+	#[must_use]
+	pub fn new(states: Vec<State>, regularizer: Regularizer) -> Self {
+		let mut individuals: Vec<(f64, State)> = states
+			.into_iter()
+			.map(|state| (regularizer.regularize(&state), state))
+			.collect();
+		individuals.sort_by(|(first, _), (second, _)| first.total_cmp(second));
+		Self { individuals }
+	}
+
+	/// Returns the number of states in the population.
+	#[must_use]
+	pub fn len(&self) -> usize { self.individuals.len() }
+
+	/// Whether the population contains no states.
+	#[must_use]
+	pub fn is_empty(&self) -> bool { self.individuals.is_empty() }
+
+	/// Returns the state with the lowest regularizer value.
+	///
+	/// # Panics
+	/// Panics if the population is empty.
+	#[must_use]
+	pub fn best(&self) -> &State { &self.individuals.first().expect("The population is empty.").1 }
+
+	/// Selects the best of `size` randomly chosen states.
+	///
+	/// # Panics
+	/// Panics if the population is empty.
+	pub fn tournament<R: Rng>(&self, size: usize, rng: &mut R) -> &State {
+		&self
+			.individuals
+			.choose_multiple(rng, size.max(1))
+			.min_by(|(first, _), (second, _)| first.total_cmp(second))
+			.expect("The population is empty.")
+			.1
+	}
+
+	/// Merges the offspring into the population and keeps only the best states, s.t. the
+	/// size of the population stays the same.
+	pub fn select(&mut self, offspring: Vec<State>, regularizer: Regularizer) {
+		let size = self.len();
+		self.individuals.extend(
+			offspring
+				.into_iter()
+				.map(|state| (regularizer.regularize(&state), state)),
+		);
+		self.individuals
+			.sort_by(|(first, _), (second, _)| first.total_cmp(second));
+		self.individuals.truncate(size);
+	}
+}
+
+/// Recombines two feasible states into a feasible child.
+///
+/// Each `DNF` of the child consists of a random selection of the clauses of both parents'
+/// respective `DNF`. Since these clauses do not cover any sample of the opposite class,
+/// only the coverage of the own class has to be repaired, which is done by greedily
+/// adding those of the remaining clauses covering the most uncovered samples.
+pub fn crossover<R: Rng>(
+	first: &State,
+	second: &State,
+	rng: &mut R,
+	positive_samples: &SampleStore,
+	negative_samples: &SampleStore,
+) -> State {
+	let positive_dnf = crossover_dnf(first.dnf(true), second.dnf(true), rng, positive_samples);
+	let negative_dnf = crossover_dnf(first.dnf(false), second.dnf(false), rng, negative_samples);
+	let child = State::new(positive_dnf, negative_dnf, positive_samples, negative_samples);
+	debug_assert!(
+		child.is_feasible(positive_samples, negative_samples),
+		"Crossover of feasible states must be feasible."
+	);
+	child
+}
+
+/// Recombines two `DNF`s which each cover all of the samples.
+fn crossover_dnf<R: Rng>(first: &DNF, second: &DNF, rng: &mut R, samples: &SampleStore) -> DNF {
+	let mut chosen: Vec<Clause> = Vec::new();
+	let mut remaining: Vec<(&Clause, Bitset)> = Vec::new();
+	let mut covered = Bitset::new(samples.len());
+	for clause in first.clauses().union(second.clauses()) {
+		let coverage = clause.covered(samples);
+		if rng.gen_bool(0.5) {
+			covered |= &coverage;
+			chosen.push(clause.clone());
+		} else {
+			remaining.push((clause, coverage));
+		}
+	}
+
+	// Repair the coverage.
+	while covered.count_ones() < samples.len() {
+		let uncovered = !&covered;
+		let (index, _) = remaining
+			.iter()
+			.enumerate()
+			.max_by_key(|(_, (_, coverage))| (coverage & &uncovered).count_ones())
+			.expect("The clauses of both parents cover all samples.");
+		let (clause, coverage) = remaining.swap_remove(index);
+		covered |= &coverage;
+		chosen.push(clause.clone());
+	}
+	DNF::new(chosen)
+}
@@ -0,0 +1,112 @@
+//! Provides the hill climbing algorithms.
+
+use log::{debug,};
+use rand::Rng;
+use rand::rngs::StdRng;
+use rayon::prelude::*;
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::moves::Modification;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Moves to the best feasible neighbour as long as it improves the current state.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BasicHillClimber;
+
+impl SearchAlgorithm for BasicHillClimber {
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		neighbourhood.improve(state, regularizer, rng)
+	}
+}
+
+/// Moves to the first improving feasible neighbour instead of the best one, only
+/// generating the neighbourhood up to that neighbour.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FirstImprovementHillClimber;
+
+impl SearchAlgorithm for FirstImprovementHillClimber {
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let current_state: &State = state;
+		let current_value = regularizer.regularize(current_state);
+		// Evaluate the regularizer first, as it is much cheaper than feasibility.
+		let first_improvement = neighbourhood
+			.operators()
+			.iter()
+			.flat_map(|operator| {
+				operator.generate_neighbourhood(
+					current_state,
+					neighbourhood.positive_samples(),
+					neighbourhood.negative_samples(),
+					rng,
+				)
+			})
+			.map(|neighbour| neighbour.modification(current_state))
+			.find(|modification| {
+				regularizer.regularize_after(current_state, modification) < current_value
+					&& neighbourhood.is_feasible_after(current_state, modification)
+			});
+		first_improvement.map_or(false, |modification| {
+			neighbourhood.apply(state, modification);
+			true
+		})
+	}
+}
+
+/// Moves to a feasible neighbour with a probability depending on how much it improves
+/// the current state.
+#[derive(Debug, Clone, Copy)]
+pub struct StochasticHillClimber {
+	/// Parameter influencing selection probability
+	selection_prob: f64,
+}
+
+impl StochasticHillClimber {
+	/// Creates a stochastic hill climber with the given selection parameter.
+	#[must_use]
+	pub const fn new(selection_prob: f64) -> Self { Self { selection_prob } }
+}
+
+impl SearchAlgorithm for StochasticHillClimber {
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		for modification in neighbourhood
+			.feasible(state, neighbourhood.operators(), rng)
+			.collect::<Vec<Modification>>()
+		{
+			let current_value = regularizer.regularize(state);
+			let neighbour_value = regularizer.regularize_after(state, &modification);
+
+			let difference = neighbour_value - current_value;
+			if difference > 0.0 {
+				debug!("====== Found neighbour worse than current solution =======");
+			}
+
+			let prob = 1.0 / (1.0 + (difference / self.selection_prob).exp());
+
+			if rng.gen_bool(prob) {
+				neighbourhood.apply(state, modification);
+				break;
+			}
+		}
+		true
+	}
+}
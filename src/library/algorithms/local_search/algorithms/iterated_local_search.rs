@@ -0,0 +1,186 @@
+//! Provides the iterated local search algorithm, its acceptance rules and restarts.
+
+use log::{
+	debug,
+	trace,
+};
+use rand::rngs::StdRng;
+use serde::{
+	Serialize,
+	Deserialize,
+};
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::neighbourhood_operator::NeighbourhoodOperator;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Perturbs the current local optimum by random feasible moves and descends to a new
+/// local optimum by basic hill climbing in each iteration.
+#[derive(Debug)]
+pub struct IteratedLocalSearch {
+	/// How to generate the moves perturbing the local optimum.
+	perturbation:            Box<dyn NeighbourhoodOperator>,
+	/// How many random feasible moves to perform per perturbation.
+	perturbation_strength:   u32,
+	/// The maximum number of hill climbing steps to descend to a local optimum.
+	local_search_iterations: u32,
+	/// Whether to continue from the new local optimum or the previous one.
+	acceptance:              Acceptance,
+	/// After how many iterations without improving the best local optimum to restart
+	/// from it, if at all.
+	restart_after:           Option<u32>,
+	/// The best local optimum, tracked from when the search is initialized.
+	restarts:                Option<Restarts>,
+}
+
+impl IteratedLocalSearch {
+	/// Creates an iterated local search with the given perturbation and acceptance.
+	#[must_use]
+	pub fn new(
+		perturbation: Box<dyn NeighbourhoodOperator>,
+		perturbation_strength: u32,
+		local_search_iterations: u32,
+		acceptance: Acceptance,
+		restart_after: Option<u32>,
+	) -> Self {
+		Self {
+			perturbation,
+			perturbation_strength,
+			local_search_iterations,
+			acceptance,
+			restart_after,
+			restarts: None,
+		}
+	}
+}
+
+impl SearchAlgorithm for IteratedLocalSearch {
+	fn initialize(
+		&mut self,
+		initial_state: &State,
+		_neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		_rng: &mut StdRng,
+	) {
+		self.restarts = Some(Restarts::new(
+			self.restart_after,
+			initial_state,
+			regularizer.regularize(initial_state),
+		));
+	}
+
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let previous_state = state.clone();
+		let previous_value = regularizer.regularize(&previous_state);
+
+		for _ in 0..self.perturbation_strength {
+			if let Some(modification) =
+				neighbourhood.random_feasible(state, std::slice::from_ref(&self.perturbation), rng)
+			{
+				neighbourhood.apply(state, modification);
+			} else {
+				break;
+			}
+		}
+		for _ in 0..self.local_search_iterations {
+			if !neighbourhood.improve(state, regularizer, rng) {
+				break;
+			}
+		}
+
+		let value = regularizer.regularize(state);
+		trace!("Found local optimum of value {} from {}.", value, previous_value);
+		let restarts = self
+			.restarts
+			.as_mut()
+			.expect("The restarts are created when the search is initialized.");
+		if let Some(best_state) = restarts.record(state, value) {
+			*state = best_state.clone();
+		} else if !self.acceptance.accept(previous_value, value) {
+			*state = previous_state;
+		}
+		true
+	}
+}
+
+/// Distinguishes rules to decide whether to continue from a new local optimum or to
+/// return to the previous one.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+pub enum Acceptance {
+	/// Only continue from strictly better local optima.
+	Better,
+	/// Continue from local optima which are not worse.
+	NotWorse,
+	/// Always continue from the new local optimum, i.e. perform a random walk over local
+	/// optima.
+	Always,
+}
+
+impl Acceptance {
+	/// Whether to continue from a local optimum of the given regularizer value, after
+	/// perturbing a local optimum of the previous value.
+	#[must_use]
+	pub fn accept(self, previous_value: f64, value: f64) -> bool {
+		match self {
+			Self::Better => value < previous_value,
+			Self::NotWorse => value <= previous_value,
+			Self::Always => true,
+		}
+	}
+}
+
+/// Keeps track of the overall best local optimum, to restart from it if the search
+/// stagnates.
+#[derive(Debug, Clone)]
+pub struct Restarts {
+	/// After how many iterations without improving the best local optimum to restart, if
+	/// at all.
+	restart_after:         Option<u32>,
+	/// The best local optimum found so far.
+	best_state:            State,
+	/// The regularizer value of the best local optimum.
+	best_value:            f64,
+	/// Iterations since the best local optimum last improved.
+	stagnating_iterations: u32,
+}
+
+impl Restarts {
+	/// Starts tracking from the initial state.
+	#[must_use]
+	pub fn new(restart_after: Option<u32>, initial_state: &State, initial_value: f64) -> Self {
+		Self {
+			restart_after,
+			best_state: initial_state.clone(),
+			best_value: initial_value,
+			stagnating_iterations: 0,
+		}
+	}
+
+	/// Records a newly found local optimum.
+	/// Returns the best local optimum if the search should be restarted from it.
+	pub fn record(&mut self, local_optimum: &State, value: f64) -> Option<&State> {
+		if value < self.best_value {
+			self.best_state = local_optimum.clone();
+			self.best_value = value;
+			self.stagnating_iterations = 0;
+			return None;
+		}
+		self.stagnating_iterations += 1;
+		match self.restart_after {
+			Some(restart_after) if self.stagnating_iterations >= restart_after => {
+				debug!("Restarting from best local optimum of value {}.", self.best_value);
+				self.stagnating_iterations = 0;
+				Some(&self.best_state)
+			},
+			_ => None,
+		}
+	}
+}
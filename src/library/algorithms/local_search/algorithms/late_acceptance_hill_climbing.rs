@@ -0,0 +1,112 @@
+//! Provides the late acceptance hill climbing algorithm and its history.
+
+use rand::rngs::StdRng;
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Proposes one random feasible neighbour per iteration and accepts it if it is not worse
+/// than the current state or the current state some iterations ago.
+#[derive(Debug, Clone)]
+pub struct LateAcceptanceHillClimber {
+	/// How many iterations ago the state to compare against was current.
+	history_length:  usize,
+	/// The history, created when the hill climber is initialized.
+	late_acceptance: Option<LateAcceptance>,
+	/// How many steps were performed.
+	iteration:       u32,
+}
+
+impl LateAcceptanceHillClimber {
+	/// Creates a late acceptance hill climber comparing against the state
+	/// `history_length` iterations ago.
+	#[must_use]
+	pub const fn new(history_length: usize) -> Self {
+		Self {
+			history_length,
+			late_acceptance: None,
+			iteration: 0,
+		}
+	}
+}
+
+impl SearchAlgorithm for LateAcceptanceHillClimber {
+	fn initialize(
+		&mut self,
+		initial_state: &State,
+		_neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		_rng: &mut StdRng,
+	) {
+		self.late_acceptance = Some(LateAcceptance::new(
+			self.history_length,
+			regularizer.regularize(initial_state),
+		));
+	}
+
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let proposal =
+			if let Some(proposal) = neighbourhood.random_feasible(state, neighbourhood.operators(), rng) {
+				proposal
+			} else {
+				return false;
+			};
+		let late_acceptance = self
+			.late_acceptance
+			.as_mut()
+			.expect("The history is created when the hill climber is initialized.");
+
+		let current_value = regularizer.regularize(state);
+		let neighbour_value = regularizer.regularize_after(state, &proposal);
+		if late_acceptance.accept(self.iteration, current_value, neighbour_value) {
+			neighbourhood.apply(state, proposal);
+		}
+		late_acceptance.record(self.iteration, regularizer.regularize(state));
+		self.iteration += 1;
+		true
+	}
+}
+
+/// Remembers the regularizer values of the current state over the last iterations.
+#[derive(Debug, Clone)]
+pub struct LateAcceptance {
+	/// The regularizer values of the last iterations, used as a ring buffer.
+	history: Vec<f64>,
+}
+
+impl LateAcceptance {
+	/// Creates a new history of the given length, filled with the regularizer value of
+	/// the initial state.
+	#[must_use]
+	pub fn new(history_length: usize, initial_value: f64) -> Self {
+		Self {
+			history: vec![initial_value; history_length.max(1)],
+		}
+	}
+
+	/// Whether to accept a neighbour of the given regularizer value in the given
+	/// iteration, i.e. whether it is not worse than the current state or the current
+	/// state `history_length` iterations ago.
+	#[must_use]
+	pub fn accept(&self, iteration: u32, current_value: f64, neighbour_value: f64) -> bool {
+		neighbour_value <= current_value || neighbour_value <= self.history[self.index(iteration)]
+	}
+
+	/// Records the regularizer value of the current state at the end of the given
+	/// iteration.
+	pub fn record(&mut self, iteration: u32, current_value: f64) {
+		let index = self.index(iteration);
+		self.history[index] = current_value;
+	}
+
+	/// The position of the given iteration in the history.
+	fn index(&self, iteration: u32) -> usize { iteration as usize % self.history.len() }
+}
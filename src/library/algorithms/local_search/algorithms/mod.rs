@@ -0,0 +1,466 @@
+//! Provides different local search algorithms and the `AlgorithmRunner` wrapper to run
+//! them all through the same interface.
+use std::time::Instant;
+use log::{debug,};
+use rand::rngs::StdRng;
+use rand::{
+	Rng,
+	SeedableRng,
+};
+use serde::{
+	Serialize,
+	Deserialize,
+};
+use crate::algorithms::local_search::algorithms::evolutionary::Evolutionary;
+use crate::algorithms::local_search::algorithms::hill_climbing::{
+	BasicHillClimber,
+	FirstImprovementHillClimber,
+	StochasticHillClimber,
+};
+use crate::algorithms::local_search::algorithms::iterated_local_search::{
+	Acceptance,
+	IteratedLocalSearch,
+};
+use crate::algorithms::local_search::algorithms::late_acceptance_hill_climbing::LateAcceptanceHillClimber;
+use crate::algorithms::local_search::algorithms::parallel_tempering::ParallelTempering;
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::algorithms::simulated_annealing::{
+	CoolingSchedule,
+	Reheating,
+	SimulatedAnnealing,
+};
+use crate::algorithms::local_search::algorithms::tabu_search::TabuSearch;
+use crate::algorithms::local_search::algorithms::variable_neighbourhood_search::VariableNeighbourhoodSearch;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use crate::algorithms::local_search::neighbourhood_operator::NeighbourhoodOperator;
+use crate::algorithms::local_search::operator_selection::{
+	OperatorSelection,
+	OperatorSelector,
+	OperatorStatistics,
+};
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+use crate::algorithms::local_search::termination_criteria::{
+	TerminationCriteria,
+	TerminationReason,
+};
+use crate::boolean_formulae::sample_store::SampleStore;
+
+pub mod evolutionary;
+pub mod hill_climbing;
+pub mod iterated_local_search;
+pub mod late_acceptance_hill_climbing;
+pub mod parallel_tempering;
+pub mod search_algorithm;
+pub mod simulated_annealing;
+pub mod tabu_search;
+pub mod variable_neighbourhood_search;
+
+/// Error Enum for setting up an `AlgorithmRunner`.
+#[derive(Debug)]
+pub enum ErrorKind {
+	/// The algorithm selects the neighbourhood operators by itself, so they must not be
+	/// selected by an `OperatorSelection` other than `All`.
+	ConflictingOperatorSelection,
+	/// The parameters of the `OperatorSelection` are out of range for the number of
+	/// neighbourhood operators.
+	InvalidOperatorSelection,
+}
+
+/// Differentiates local search algorithms.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+pub enum Algorithm {
+	/// Most basic hill climbing algorithm.
+	BasicHillClimber {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations: u32,
+	},
+	/// Hill climbing which moves to the first improving feasible neighbour instead of the
+	/// best one, only generating the neighbourhood up to that neighbour.
+	FirstImprovementHillClimber {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations: u32,
+	},
+	/// Stochastic
+	StochasticHillClimber {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations: u32,
+		/// Parameter influencing selection probability
+		selection_prob: f64,
+	},
+	/// Simulated annealing, proposes one random feasible neighbour per iteration and
+	/// accepts it by the Metropolis criterion under a decreasing temperature.
+	SimulatedAnnealing {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations:      u32,
+		/// The temperature to start with.
+		initial_temperature: f64,
+		/// How to lower the temperature over the iterations.
+		cooling_schedule:    CoolingSchedule,
+		/// When to raise the temperature again, if at all.
+		reheating:           Option<Reheating>,
+	},
+	/// Tabu search, always moves to the best feasible neighbour whose inserted or removed
+	/// literals were not touched within the last iterations.
+	TabuSearch {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations: u32,
+		/// For how many iterations the literals touched by a move stay tabu.
+		tenure:         u32,
+		/// Whether to allow tabu moves which lead to a better state than found so far.
+		aspiration:     bool,
+	},
+	/// Late acceptance hill climbing, proposes one random feasible neighbour per
+	/// iteration and accepts it if it is not worse than the current state or the current
+	/// state some iterations ago.
+	LateAcceptanceHillClimber {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations: u32,
+		/// How many iterations ago the state to compare against was current.
+		history_length: usize,
+	},
+	/// Iterated local search, perturbs the current local optimum by random feasible moves
+	/// and descends to a new local optimum by basic hill climbing in each iteration.
+	IteratedLocalSearch {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations:          u32,
+		/// How to generate the moves perturbing the local optimum.
+		perturbation:            NeighbourhoodGenerator,
+		/// How many random feasible moves to perform per perturbation.
+		perturbation_strength:   u32,
+		/// The maximum number of hill climbing steps to descend to a local optimum.
+		local_search_iterations: u32,
+		/// Whether to continue from the new local optimum or the previous one.
+		acceptance:              Acceptance,
+		/// After how many iterations without improving the best local optimum to restart
+		/// from it, if at all.
+		restart_after:           Option<u32>,
+	},
+	/// Evolutionary algorithm, recombines and mutates a population of feasible states and
+	/// keeps the best of parents and offspring in each iteration.
+	Evolutionary {
+		/// Abort algorithm after a maximum number of iterations (generations).
+		max_iterations:        u32,
+		/// How many states the population consists of, also the number of offspring per
+		/// generation.
+		population_size:       usize,
+		/// How many randomly chosen states compete for being selected as a parent.
+		tournament_size:       usize,
+		/// Probability of an offspring being created by crossover of two parents instead
+		/// of copying one parent, between 0 and 1.
+		crossover_probability: f64,
+		/// Probability of an offspring being mutated, between 0 and 1.
+		mutation_probability:  f64,
+		/// How many random feasible moves of the neighbourhood generators a mutation
+		/// consists of.
+		mutation_strength:     u32,
+	},
+	/// Parallel tempering, explores replicas of the state at different temperatures in
+	/// parallel, each proposing one random feasible neighbour per iteration and accepting
+	/// it by the Metropolis criterion, and periodically exchanges the states of replicas.
+	ParallelTempering {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations:  u32,
+		/// How many replicas to explore.
+		replica_count:   usize,
+		/// The temperature of the coldest replica, must be positive and finite.
+		min_temperature: f64,
+		/// The temperature of the hottest replica, must be positive and finite. The
+		/// temperatures in between are spaced geometrically.
+		max_temperature: f64,
+		/// After how many iterations to attempt exchanging the states of replicas
+		/// adjacent in temperature.
+		swap_interval:   u32,
+	},
+	/// Variable neighbourhood search, uses the neighbourhood generators in the configured
+	/// order as increasingly large neighbourhoods: Moves to the best neighbour of the
+	/// current neighbourhood if it improves the current state and returns to the first
+	/// neighbourhood, otherwise escalates to the next one.
+	VariableNeighbourhoodSearch {
+		/// Abort algorithm after a maximum number of iterations.
+		max_iterations: u32,
+	},
+}
+
+impl Algorithm {
+	/// Returns the maximum number of iterations of the algorithm.
+	#[must_use]
+	pub const fn max_iterations(&self) -> u32 {
+		match *self {
+			Self::BasicHillClimber { max_iterations }
+			| Self::FirstImprovementHillClimber { max_iterations }
+			| Self::StochasticHillClimber { max_iterations, .. }
+			| Self::SimulatedAnnealing { max_iterations, .. }
+			| Self::TabuSearch { max_iterations, .. }
+			| Self::LateAcceptanceHillClimber { max_iterations, .. }
+			| Self::IteratedLocalSearch { max_iterations, .. }
+			| Self::Evolutionary { max_iterations, .. }
+			| Self::ParallelTempering { max_iterations, .. }
+			| Self::VariableNeighbourhoodSearch { max_iterations } => max_iterations,
+		}
+	}
+
+	/// Returns whether the parameters of the algorithm are in range.
+	#[must_use]
+	pub fn is_valid(&self) -> bool {
+		match *self {
+			Self::Evolutionary {
+				crossover_probability,
+				mutation_probability,
+				..
+			} => [crossover_probability, mutation_probability]
+				.iter()
+				.all(|probability| (0.0..=1.0).contains(probability)),
+			Self::ParallelTempering {
+				min_temperature,
+				max_temperature,
+				..
+			} => [min_temperature, max_temperature]
+				.iter()
+				.all(|temperature| temperature.is_finite() && *temperature > 0.0),
+			Self::SimulatedAnnealing {
+				initial_temperature,
+				cooling_schedule,
+				reheating,
+				..
+			} => {
+				initial_temperature.is_finite()
+					&& initial_temperature >= 0.0
+					&& cooling_schedule.is_valid()
+					&& reheating.map_or(true, |reheating| reheating.is_valid())
+			},
+			_ => true,
+		}
+	}
+
+	/// Creates the built-in `SearchAlgorithm` configured by this variant.
+	#[must_use]
+	pub fn into_search_algorithm(self) -> Box<dyn SearchAlgorithm> {
+		match self {
+			Self::BasicHillClimber { .. } => Box::new(BasicHillClimber),
+			Self::FirstImprovementHillClimber { .. } => Box::new(FirstImprovementHillClimber),
+			Self::StochasticHillClimber { selection_prob, .. } => {
+				Box::new(StochasticHillClimber::new(selection_prob))
+			},
+			Self::SimulatedAnnealing {
+				initial_temperature,
+				cooling_schedule,
+				reheating,
+				..
+			} => Box::new(SimulatedAnnealing::new(
+				initial_temperature,
+				cooling_schedule,
+				reheating,
+			)),
+			Self::TabuSearch {
+				tenure, aspiration, ..
+			} => Box::new(TabuSearch::new(tenure, aspiration)),
+			Self::LateAcceptanceHillClimber { history_length, .. } => {
+				Box::new(LateAcceptanceHillClimber::new(history_length))
+			},
+			Self::IteratedLocalSearch {
+				perturbation,
+				perturbation_strength,
+				local_search_iterations,
+				acceptance,
+				restart_after,
+				..
+			} => Box::new(IteratedLocalSearch::new(
+				Box::new(perturbation),
+				perturbation_strength,
+				local_search_iterations,
+				acceptance,
+				restart_after,
+			)),
+			Self::Evolutionary {
+				population_size,
+				tournament_size,
+				crossover_probability,
+				mutation_probability,
+				mutation_strength,
+				..
+			} => Box::new(Evolutionary::new(
+				population_size,
+				tournament_size,
+				crossover_probability,
+				mutation_probability,
+				mutation_strength,
+			)),
+			Self::ParallelTempering {
+				replica_count,
+				min_temperature,
+				max_temperature,
+				swap_interval,
+				..
+			} => Box::new(ParallelTempering::new(
+				replica_count,
+				min_temperature,
+				max_temperature,
+				swap_interval,
+			)),
+			Self::VariableNeighbourhoodSearch { .. } => Box::new(VariableNeighbourhoodSearch::default()),
+		}
+	}
+}
+
+/// Applies a `SearchAlgorithm` to a state, w.r.t. the samples, neighbourhood operators
+/// and the regularizer, until one of the termination criteria is met.
+#[derive(Debug)]
+pub struct AlgorithmRunner<'a> {
+	/// The algorithm to use.
+	algorithm:             Box<dyn SearchAlgorithm + 'a>,
+	/// The current state of the two-DNF-state.
+	current_state:         State,
+	/// The neighbourhood of the current state.
+	neighbourhood:         Neighbourhood<'a>,
+	/// By which strategy to judge feasible solutions.
+	regularizer:           Regularizer,
+	/// How many iterations of the algorithm have already elapsed.
+	iterations:            u32,
+	/// When to terminate besides the algorithm's own criteria.
+	termination:           TerminationCriteria,
+	/// Why the algorithm terminated, if it did.
+	termination_reason:    Option<TerminationReason>,
+	/// When the runner was created.
+	start:                 Instant,
+	/// The best regularizer value of the current state so far.
+	best_value:            f64,
+	/// Iterations since the best regularizer value last improved.
+	stagnating_iterations: u32,
+	/// The source of all randomness of the algorithm.
+	rng:                   StdRng,
+	/// Selects the neighbourhood operators evaluated in each step.
+	operator_selector:     OperatorSelector,
+}
+
+impl<'a> AlgorithmRunner<'a> {
+	/// Creates a new algorithms runner and initializes the algorithm with the initial
+	/// state.
+	/// Runners created with the same arguments, including the `seed`, perform the same
+	/// steps.
+	#[allow(clippy::too_many_arguments)]
+	#[must_use]
+	pub fn new(
+		mut algorithm: Box<dyn SearchAlgorithm + 'a>,
+		initial_state: State,
+		positive_samples: &'a SampleStore,
+		negative_samples: &'a SampleStore,
+		neighbourhood_operators: Vec<Box<dyn NeighbourhoodOperator>>,
+		regularizer: Regularizer,
+		termination: TerminationCriteria,
+		seed: u64,
+	) -> Self {
+		debug_assert!(
+			initial_state.is_feasible(positive_samples, negative_samples),
+			"The initial state must be feasible."
+		);
+		let operator_selector = OperatorSelector::new(OperatorSelection::All, neighbourhood_operators.len());
+		let neighbourhood = Neighbourhood::new(neighbourhood_operators, positive_samples, negative_samples);
+		let mut rng = StdRng::seed_from_u64(seed);
+		algorithm.initialize(&initial_state, &neighbourhood, regularizer, &mut rng);
+		Self {
+			algorithm,
+			best_value: regularizer.regularize(&initial_state),
+			current_state: initial_state,
+			neighbourhood,
+			regularizer,
+			iterations: 0,
+			termination,
+			termination_reason: None,
+			start: Instant::now(),
+			stagnating_iterations: 0,
+			rng,
+			operator_selector,
+		}
+	}
+
+	/// Selects the neighbourhood operators evaluated in each step by the given strategy
+	/// instead of evaluating all of them.
+	///
+	/// # Errors
+	/// Will return `ErrorKind::ConflictingOperatorSelection` if the algorithm selects the
+	/// operators by itself and the strategy is not `OperatorSelection::All`, and
+	/// `ErrorKind::InvalidOperatorSelection` if the parameters of the strategy are out of
+	/// range.
+	pub fn with_operator_selection(self, operator_selection: OperatorSelection) -> Result<Self, ErrorKind> {
+		if self.algorithm.selects_operators() && !matches!(operator_selection, OperatorSelection::All) {
+			return Err(ErrorKind::ConflictingOperatorSelection);
+		}
+		let operator_count = self.operator_selector.operator_count();
+		if !operator_selection.is_valid(operator_count) {
+			return Err(ErrorKind::InvalidOperatorSelection);
+		}
+		Ok(Self {
+			operator_selector: OperatorSelector::new(operator_selection, operator_count),
+			..self
+		})
+	}
+
+	/// Performs one step of the algorithm.
+	/// Returns `false` when the algorithm has terminated.
+	pub fn step(&mut self) -> bool {
+		if self.termination_reason.is_some() {
+			return false;
+		}
+		let reason = self.termination.check(
+			self.iterations,
+			self.start.elapsed(),
+			self.feasibility_evaluations(),
+			self.stagnating_iterations,
+			self.best_value,
+		);
+		if let Some(reason) = reason {
+			debug!("Terminating after {} iterations: {:?}", self.iterations, reason);
+			self.termination_reason = Some(reason);
+			return false;
+		}
+		let previous_value = self.regularizer.regularize(&self.current_state);
+		let mut rng = StdRng::seed_from_u64(self.rng.gen());
+		self.neighbourhood.select(self.operator_selector.select(&mut rng));
+		// With adaptive operator selection, only terminate once no operator yields an
+		// acceptable neighbour.
+		if !self.algorithm.step(
+			&mut self.current_state,
+			&self.neighbourhood,
+			self.regularizer,
+			&mut rng,
+		) && self.operator_selector.exhaust()
+		{
+			debug!(
+				"Terminating after {} iterations: no acceptable neighbour.",
+				self.iterations
+			);
+			self.termination_reason = Some(TerminationReason::NoAcceptableNeighbour);
+			return false;
+		}
+
+		self.iterations += 1;
+		let value = self.regularizer.regularize(&self.current_state);
+		self.operator_selector.reward(value < previous_value);
+		if value < self.best_value {
+			self.best_value = value;
+			self.stagnating_iterations = 0;
+		} else {
+			self.stagnating_iterations += 1;
+		}
+		true
+	}
+
+	/// Returns the current state of the algorithm.
+	pub const fn current_state(&self) -> &State { &self.current_state }
+
+	/// Returns the current iteration count of the algorithm.
+	pub const fn iteration(&self) -> u32 { self.iterations }
+
+	/// Returns how many neighbours were evaluated for feasibility so far.
+	pub fn feasibility_evaluations(&self) -> u64 { self.neighbourhood.feasibility_evaluations() }
+
+	/// Returns how the neighbourhood operators performed, or `None` if they are not
+	/// selected adaptively.
+	#[must_use]
+	pub fn operator_statistics(&self) -> Option<&[OperatorStatistics]> { self.operator_selector.statistics() }
+
+	/// Returns why the algorithm terminated, or `None` if it has not terminated yet.
+	pub const fn termination_reason(&self) -> Option<TerminationReason> { self.termination_reason }
+}
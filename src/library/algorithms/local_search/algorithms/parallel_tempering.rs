@@ -0,0 +1,208 @@
+//! Provides the parallel tempering algorithm and its replicas.
+
+use log::trace;
+use rand::rngs::StdRng;
+use rand::{
+	Rng,
+	SeedableRng,
+};
+use rayon::prelude::*;
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::algorithms::simulated_annealing::metropolis;
+use crate::algorithms::local_search::neighbourhood::{
+	draw_seeds,
+	Neighbourhood,
+};
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Explores replicas of the state at different temperatures in parallel, each proposing
+/// one random feasible neighbour per iteration and accepting it by the Metropolis
+/// criterion, and periodically exchanges the states of replicas.
+#[derive(Debug, Clone)]
+pub struct ParallelTempering {
+	/// How many replicas to explore.
+	replica_count:   usize,
+	/// The temperature of the coldest replica, must be positive and finite.
+	min_temperature: f64,
+	/// The temperature of the hottest replica, must be positive and finite.
+	max_temperature: f64,
+	/// After how many iterations to attempt exchanging the states of replicas adjacent
+	/// in temperature.
+	swap_interval:   u32,
+	/// The replicas, created when the algorithm is initialized.
+	replicas:        Option<Replicas>,
+	/// How many steps were performed.
+	iteration:       u32,
+}
+
+impl ParallelTempering {
+	/// Creates a parallel tempering with temperatures spaced geometrically between the
+	/// minimum and maximum temperature.
+	#[must_use]
+	pub const fn new(
+		replica_count: usize,
+		min_temperature: f64,
+		max_temperature: f64,
+		swap_interval: u32,
+	) -> Self {
+		Self {
+			replica_count,
+			min_temperature,
+			max_temperature,
+			swap_interval,
+			replicas: None,
+			iteration: 0,
+		}
+	}
+}
+
+impl SearchAlgorithm for ParallelTempering {
+	fn initialize(
+		&mut self,
+		initial_state: &State,
+		_neighbourhood: &Neighbourhood,
+		_regularizer: Regularizer,
+		_rng: &mut StdRng,
+	) {
+		self.replicas = Some(Replicas::new(
+			initial_state,
+			self.replica_count,
+			self.min_temperature,
+			self.max_temperature,
+		));
+	}
+
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let replicas = self
+			.replicas
+			.as_mut()
+			.expect("The replicas are created when the algorithm is initialized.");
+
+		let seeds = draw_seeds(rng, replicas.replicas_mut().len());
+		replicas
+			.replicas_mut()
+			.par_iter_mut()
+			.zip(seeds)
+			.for_each(|(replica, seed)| {
+				let mut rng = StdRng::seed_from_u64(seed);
+				if let Some(proposal) =
+					neighbourhood.random_feasible(&replica.state, neighbourhood.operators(), &mut rng)
+				{
+					let current_value = regularizer.regularize(&replica.state);
+					let neighbour_value = regularizer.regularize_after(&replica.state, &proposal);
+					let difference = neighbour_value - current_value;
+					if metropolis(difference, replica.temperature, &mut rng) {
+						neighbourhood.apply(&mut replica.state, proposal);
+					}
+				}
+			});
+		self.iteration += 1;
+		if self.iteration % self.swap_interval.max(1) == 0 {
+			replicas.swap(regularizer, rng);
+		}
+
+		*state = replicas.best(regularizer).clone();
+		true
+	}
+}
+
+/// A state which is explored at a fixed temperature.
+#[derive(Debug, Clone)]
+pub struct Replica {
+	/// The current state of the replica.
+	pub state:       State,
+	/// The temperature the replica accepts moves at.
+	pub temperature: f64,
+}
+
+/// Replicas of a state at temperatures spaced geometrically between a minimum and a
+/// maximum temperature, ordered by ascending temperature.
+#[derive(Debug, Clone)]
+pub struct Replicas {
+	/// The replicas, coldest first.
+	replicas: Vec<Replica>,
+}
+
+impl Replicas {
+	/// Creates `count` replicas of the initial state.
+	///
+	/// # Panics
+	/// Panics if a temperature is not positive and finite.
+	#[must_use]
+	pub fn new(initial_state: &State, count: usize, min_temperature: f64, max_temperature: f64) -> Self {
+		assert!(
+			[min_temperature, max_temperature]
+				.iter()
+				.all(|temperature| temperature.is_finite() && *temperature > 0.0),
+			"The temperatures must be positive and finite, but are {} and {}.",
+			min_temperature,
+			max_temperature
+		);
+		let count = count.max(1);
+		let replicas = (0..count)
+			.map(|index| {
+				#[allow(clippy::cast_precision_loss)]
+				let exponent = if count > 1 {
+					index as f64 / (count - 1) as f64
+				} else {
+					0.0
+				};
+				Replica {
+					state:       initial_state.clone(),
+					temperature: min_temperature * (max_temperature / min_temperature).powf(exponent),
+				}
+			})
+			.collect();
+		Self { replicas }
+	}
+
+	/// Returns the replicas for modification, coldest first.
+	pub fn replicas_mut(&mut self) -> &mut [Replica] { &mut self.replicas }
+
+	/// Attempts to swap the states of all pairs of replicas adjacent in temperature,
+	/// each with the Metropolis criterion for replica exchange.
+	pub fn swap<R: Rng>(&mut self, regularizer: Regularizer, rng: &mut R) {
+		for index in 1..self.replicas.len() {
+			let (colder, hotter) = self.replicas.split_at_mut(index);
+			let (colder, hotter) = (&mut colder[index - 1], &mut hotter[0]);
+			let colder_value = regularizer.regularize(&colder.state);
+			let hotter_value = regularizer.regularize(&hotter.state);
+			let difference =
+				(1.0 / colder.temperature - 1.0 / hotter.temperature) * (colder_value - hotter_value);
+			if metropolis(-difference, 1.0, rng) {
+				trace!(
+					"Swapping states at temperatures {} and {}.",
+					colder.temperature,
+					hotter.temperature
+				);
+				std::mem::swap(&mut colder.state, &mut hotter.state);
+			}
+		}
+	}
+
+	/// Returns the state with the lowest regularizer value across all replicas.
+	///
+	/// # Panics
+	/// Panics if there are no replicas, which can not happen by construction.
+	#[must_use]
+	pub fn best(&self, regularizer: Regularizer) -> &State {
+		&self
+			.replicas
+			.iter()
+			.min_by(|first, second| {
+				regularizer
+					.regularize(&first.state)
+					.total_cmp(&regularizer.regularize(&second.state))
+			})
+			.expect("There is at least one replica.")
+			.state
+	}
+}
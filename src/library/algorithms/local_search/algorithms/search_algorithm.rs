@@ -0,0 +1,41 @@
+//! Provides the interface of the search algorithms run by the `AlgorithmRunner`.
+
+use std::fmt::Debug;
+use rand::rngs::StdRng;
+
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// A strategy to move a feasible state through its neighbourhood, one step at a time.
+///
+/// The `AlgorithmRunner` keeps track of the iterations, the termination criteria and
+/// the best found state, so implementations only decide where to move in each step.
+/// All randomness must be drawn from the provided `rng` to keep runs reproducible.
+pub trait SearchAlgorithm: Debug {
+	/// Prepares the algorithm to start from the initial state.
+	/// Is called once by the `AlgorithmRunner` before the first step.
+	fn initialize(
+		&mut self,
+		_initial_state: &State,
+		_neighbourhood: &Neighbourhood,
+		_regularizer: Regularizer,
+		_rng: &mut StdRng,
+	) {
+	}
+
+	/// Performs one step of the algorithm, which may move the current state to another
+	/// feasible state.
+	/// Returns `false` if the algorithm can not continue from the current state.
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool;
+
+	/// Whether the algorithm selects which neighbourhood operators to evaluate by itself,
+	/// s.t. it must be given all of them in each step.
+	fn selects_operators(&self) -> bool { false }
+}
@@ -0,0 +1,260 @@
+//! Provides the simulated annealing algorithm and its temperature control.
+
+use log::{
+	debug,
+	trace,
+};
+use rand::Rng;
+use rand::rngs::StdRng;
+use serde::{
+	Serialize,
+	Deserialize,
+};
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Proposes one random feasible neighbour per iteration and accepts it by the Metropolis
+/// criterion under a decreasing temperature.
+#[derive(Debug, Clone)]
+pub struct SimulatedAnnealing {
+	/// The temperature control.
+	annealer: Annealer,
+}
+
+impl SimulatedAnnealing {
+	/// Creates a simulated annealing starting at the initial temperature.
+	#[must_use]
+	pub const fn new(
+		initial_temperature: f64,
+		cooling_schedule: CoolingSchedule,
+		reheating: Option<Reheating>,
+	) -> Self {
+		Self {
+			annealer: Annealer::new(initial_temperature, cooling_schedule, reheating),
+		}
+	}
+}
+
+impl SearchAlgorithm for SimulatedAnnealing {
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let proposal =
+			if let Some(proposal) = neighbourhood.random_feasible(state, neighbourhood.operators(), rng) {
+				proposal
+			} else {
+				return false;
+			};
+
+		let current_value = regularizer.regularize(state);
+		let neighbour_value = regularizer.regularize_after(state, &proposal);
+		let difference = neighbour_value - current_value;
+		let accepted = self.annealer.accept(difference, rng);
+		trace!(
+			"Proposed neighbour with difference {} at temperature {}, accepted: {}.",
+			difference,
+			self.annealer.temperature(),
+			accepted
+		);
+		if accepted {
+			neighbourhood.apply(state, proposal);
+		}
+		self.annealer.cool(accepted, regularizer.regularize(state));
+		true
+	}
+}
+
+/// Distinguishes strategies to lower the temperature over the iterations.
+///
+/// `k` denotes the number of iterations since the start (or the last reheating) and `T_0`
+/// the temperature at that point.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+pub enum CoolingSchedule {
+	/// `T_k = T_0 * factor^k`.
+	Geometric {
+		/// Multiplies the temperature in each iteration, must be in `(0, 1]`.
+		factor: f64,
+	},
+	/// `T_k = max(T_0 - k * decrement, 0)`.
+	Linear {
+		/// Is subtracted from the temperature in each iteration.
+		decrement: f64,
+	},
+	/// `T_k = T_0 * ln(2) / ln(k + 2)`.
+	Logarithmic,
+	/// Adapts the temperature to the rate of accepted moves:
+	/// After each window of iterations the temperature is multiplied by `factor` if more
+	/// moves than the target rate were accepted, and divided by it otherwise.
+	Adaptive {
+		/// Multiplies or divides the temperature, must be in `(0, 1]`.
+		factor:                 f64,
+		/// The desired ratio of accepted to proposed moves.
+		target_acceptance_rate: f64,
+		/// How many iterations to observe before adapting the temperature.
+		window:                 u32,
+	},
+}
+
+impl CoolingSchedule {
+	/// Returns whether the parameters of the schedule are in range, s.t. the temperature
+	/// stays finite and non-negative.
+	#[must_use]
+	pub fn is_valid(&self) -> bool {
+		match *self {
+			Self::Geometric { factor } => factor > 0.0 && factor <= 1.0,
+			Self::Linear { decrement } => decrement.is_finite() && decrement >= 0.0,
+			Self::Logarithmic => true,
+			Self::Adaptive {
+				factor,
+				target_acceptance_rate,
+				..
+			} => factor > 0.0 && factor <= 1.0 && (0.0..=1.0).contains(&target_acceptance_rate),
+		}
+	}
+}
+
+/// Raises the temperature again if the search stagnates.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+pub struct Reheating {
+	/// After how many iterations without improving the best found regularizer value to
+	/// reheat.
+	pub stagnation:  u32,
+	/// The temperature to reheat to, from which the cooling schedule starts over.
+	pub temperature: f64,
+}
+
+impl Reheating {
+	/// Returns whether the parameters of the reheating are in range, i.e. it waits for
+	/// at least one stagnating iteration and reheats to a finite positive temperature.
+	#[must_use]
+	pub fn is_valid(&self) -> bool {
+		self.stagnation > 0 && self.temperature.is_finite() && self.temperature > 0.0
+	}
+}
+
+/// Keeps track of the temperature of a simulated annealing run.
+#[derive(Debug, Clone)]
+pub struct Annealer {
+	/// How to lower the temperature.
+	cooling_schedule:      CoolingSchedule,
+	/// When and how to reheat.
+	reheating:             Option<Reheating>,
+	/// The temperature the cooling schedule started from.
+	start_temperature:     f64,
+	/// The current temperature.
+	temperature:           f64,
+	/// Iterations since the cooling schedule started.
+	cooling_iterations:    u32,
+	/// Accepted moves since the last adaptation of the temperature.
+	accepted_in_window:    u32,
+	/// The best regularizer value found so far.
+	best_value:            Option<f64>,
+	/// Iterations since the best regularizer value last improved.
+	stagnating_iterations: u32,
+}
+
+impl Annealer {
+	/// Creates a new annealer starting at the initial temperature.
+	#[must_use]
+	pub const fn new(
+		initial_temperature: f64,
+		cooling_schedule: CoolingSchedule,
+		reheating: Option<Reheating>,
+	) -> Self {
+		Self {
+			cooling_schedule,
+			reheating,
+			start_temperature: initial_temperature,
+			temperature: initial_temperature,
+			cooling_iterations: 0,
+			accepted_in_window: 0,
+			best_value: None,
+			stagnating_iterations: 0,
+		}
+	}
+
+	/// Returns the current temperature.
+	#[must_use]
+	pub const fn temperature(&self) -> f64 { self.temperature }
+
+	/// Decides by the Metropolis criterion whether to accept a move changing the
+	/// regularizer value by `difference` at the current temperature.
+	pub fn accept<R: Rng>(&self, difference: f64, rng: &mut R) -> bool {
+		metropolis(difference, self.temperature, rng)
+	}
+
+	/// Advances the temperature by one iteration, given whether the proposed move was
+	/// accepted and the regularizer value of the current state afterwards.
+	pub fn cool(&mut self, accepted: bool, current_value: f64) {
+		if accepted {
+			self.accepted_in_window += 1;
+		}
+		if self.best_value.map_or(true, |best| current_value < best) {
+			self.best_value = Some(current_value);
+			self.stagnating_iterations = 0;
+		} else {
+			self.stagnating_iterations += 1;
+		}
+
+		if let Some(reheating) = self.reheating {
+			if self.stagnating_iterations >= reheating.stagnation {
+				debug!(
+					"Reheating from temperature {} to {}.",
+					self.temperature, reheating.temperature
+				);
+				self.start_temperature = reheating.temperature;
+				self.temperature = reheating.temperature;
+				self.cooling_iterations = 0;
+				self.accepted_in_window = 0;
+				self.stagnating_iterations = 0;
+				return;
+			}
+		}
+
+		self.cooling_iterations += 1;
+		let k = f64::from(self.cooling_iterations);
+		self.temperature = match self.cooling_schedule {
+			CoolingSchedule::Geometric { factor } => self.temperature * factor,
+			CoolingSchedule::Linear { decrement } => k.mul_add(-decrement, self.start_temperature).max(0.0),
+			CoolingSchedule::Logarithmic => self.start_temperature * 2_f64.ln() / (k + 2.0).ln(),
+			CoolingSchedule::Adaptive {
+				factor,
+				target_acceptance_rate,
+				window,
+			} => {
+				if self.cooling_iterations % window.max(1) == 0 {
+					let acceptance_rate = f64::from(self.accepted_in_window) / f64::from(window.max(1));
+					self.accepted_in_window = 0;
+					if acceptance_rate > target_acceptance_rate {
+						self.temperature * factor
+					} else {
+						self.temperature / factor
+					}
+				} else {
+					self.temperature
+				}
+			},
+		};
+	}
+}
+
+/// Decides by the Metropolis criterion whether to accept a move changing the regularizer
+/// value by `difference` at the given temperature.
+/// Improvements are always accepted, deteriorations with probability
+/// `exp(-difference / temperature)`.
+pub fn metropolis<R: Rng>(difference: f64, temperature: f64, rng: &mut R) -> bool {
+	if difference <= 0.0 {
+		true
+	} else if temperature <= 0.0 {
+		false
+	} else {
+		rng.gen_bool((-difference / temperature).exp())
+	}
+}
@@ -0,0 +1,145 @@
+//! Provides the tabu search algorithm and its tabu list.
+
+use std::collections::HashMap;
+use log::{
+	debug,
+	trace,
+};
+use rand::rngs::StdRng;
+use rayon::prelude::*;
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::moves::Attribute;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Always moves to the best feasible neighbour whose inserted or removed literals were
+/// not touched within the last iterations.
+#[derive(Debug, Clone)]
+pub struct TabuSearch {
+	/// For how many iterations the literals touched by a move stay tabu.
+	tenure:     u32,
+	/// Whether to allow tabu moves which lead to a better state than found so far.
+	aspiration: bool,
+	/// The tabu list, created when the search is initialized.
+	tabu_list:  Option<TabuList>,
+	/// How many steps were performed.
+	iteration:  u32,
+}
+
+impl TabuSearch {
+	/// Creates a tabu search with the given tenure.
+	#[must_use]
+	pub const fn new(tenure: u32, aspiration: bool) -> Self {
+		Self {
+			tenure,
+			aspiration,
+			tabu_list: None,
+			iteration: 0,
+		}
+	}
+}
+
+impl SearchAlgorithm for TabuSearch {
+	fn initialize(
+		&mut self,
+		initial_state: &State,
+		_neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		_rng: &mut StdRng,
+	) {
+		self.tabu_list = Some(TabuList::new(self.tenure, regularizer.regularize(initial_state)));
+	}
+
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let tabu_list = self
+			.tabu_list
+			.as_mut()
+			.expect("The tabu list is created when the search is initialized.");
+		let (iteration, aspiration) = (self.iteration, self.aspiration);
+		self.iteration += 1;
+
+		let current_state: &State = state;
+		let best_neighbour = neighbourhood
+			.moves(current_state, neighbourhood.operators(), rng)
+			.filter_map(|neighbour| {
+				let modification = neighbour.modification(current_state);
+				if !neighbourhood.is_feasible_after(current_state, &modification) {
+					return None;
+				}
+				let value = regularizer.regularize_after(current_state, &modification);
+				let attributes = neighbour.attributes(current_state);
+				let allowed =
+					!tabu_list.is_tabu(&attributes, iteration) || (aspiration && tabu_list.aspires(value));
+				allowed.then(|| (value, attributes, modification))
+			})
+			.min_by(|(first, ..), (second, ..)| first.total_cmp(second));
+		if let Some((value, attributes, modification)) = best_neighbour {
+			trace!("Moving to neighbour of value {}.", value);
+			tabu_list.record(attributes, iteration, value);
+			neighbourhood.apply(state, modification);
+			true
+		} else {
+			debug!("All feasible neighbours are tabu.");
+			false
+		}
+	}
+}
+
+/// Remembers which literals were recently inserted or removed, s.t. moves touching them
+/// again can be forbidden for a number of iterations.
+#[derive(Debug, Clone)]
+pub struct TabuList {
+	/// For how many iterations a touched literal stays tabu.
+	tenure:     u32,
+	/// The iteration until which (exclusively) each literal is tabu.
+	tabu_until: HashMap<Attribute, u32>,
+	/// The best regularizer value found so far, used to decide on aspiration.
+	best_value: f64,
+}
+
+impl TabuList {
+	/// Creates an empty tabu list for a search starting at a state of the given
+	/// regularizer value.
+	#[must_use]
+	pub fn new(tenure: u32, initial_value: f64) -> Self {
+		Self {
+			tenure,
+			tabu_until: HashMap::new(),
+			best_value: initial_value,
+		}
+	}
+
+	/// Whether any of the attributes is tabu in the given iteration.
+	#[must_use]
+	pub fn is_tabu(&self, attributes: &[Attribute], iteration: u32) -> bool {
+		attributes.iter().any(|attribute| {
+			self.tabu_until
+				.get(attribute)
+				.map_or(false, |until| iteration < *until)
+		})
+	}
+
+	/// Whether a move leading to the given regularizer value is allowed despite being
+	/// tabu, i.e. whether it improves upon the best value found so far.
+	#[must_use]
+	pub fn aspires(&self, value: f64) -> bool { value < self.best_value }
+
+	/// Makes the attributes of a performed move tabu and records the resulting
+	/// regularizer value.
+	pub fn record(&mut self, attributes: Vec<Attribute>, iteration: u32, value: f64) {
+		let until = iteration.saturating_add(self.tenure).saturating_add(1);
+		for attribute in attributes {
+			self.tabu_until.insert(attribute, until);
+		}
+		self.tabu_until.retain(|_, until| iteration < *until);
+		self.best_value = self.best_value.min(value);
+	}
+}
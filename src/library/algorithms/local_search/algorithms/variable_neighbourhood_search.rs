@@ -0,0 +1,50 @@
+//! Provides the variable neighbourhood search algorithm.
+
+use log::{
+	debug,
+	trace,
+};
+use rand::rngs::StdRng;
+
+use crate::algorithms::local_search::algorithms::search_algorithm::SearchAlgorithm;
+use crate::algorithms::local_search::neighbourhood::Neighbourhood;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+
+/// Uses the neighbourhood operators in the configured order as increasingly large
+/// neighbourhoods: Moves to the best neighbour of the current neighbourhood if it
+/// improves the current state and returns to the first neighbourhood, otherwise
+/// escalates to the next one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VariableNeighbourhoodSearch {
+	/// The index of the neighbourhood operator currently used.
+	neighbourhood_index: usize,
+}
+
+impl SearchAlgorithm for VariableNeighbourhoodSearch {
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		let operator = if let Some(operator) = neighbourhood.operators().get(self.neighbourhood_index) {
+			std::slice::from_ref(operator)
+		} else {
+			debug!("None of the neighbourhoods contains an improving neighbour.");
+			return false;
+		};
+
+		if let Some(modification) = neighbourhood.best_improvement(state, operator, regularizer, rng) {
+			neighbourhood.apply(state, modification);
+			self.neighbourhood_index = 0;
+		} else {
+			trace!("Escalating to neighbourhood {}.", self.neighbourhood_index + 1);
+			self.neighbourhood_index += 1;
+		}
+		true
+	}
+
+	fn selects_operators(&self) -> bool { true }
+}
@@ -5,205 +5,461 @@ use std::fs::{
 	create_dir_all,
 	File,
 };
-use std::hash::Hash;
 use std::io::{Write,};
 use std::path::{Path,};
-use bitmaps::{
-	Bits,
-	BitsImpl,
-};
+use std::time::Instant;
 use chrono::Utc;
 use log::{debug,};
+use rand::{
+	Rng,
+	thread_rng,
+};
 use rayon::prelude::*;
-use crate::algorithms::local_search::algorithms::AlgorithmRunner;
 
+use crate::algorithms::local_search::algorithms::Algorithm;
+use crate::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use crate::algorithms::local_search::operator_registry::OperatorConfig;
+use crate::algorithms::local_search::operator_selection::OperatorSelection;
 use crate::algorithms::local_search::run_config::RunConfig;
 use crate::algorithms::local_search::state::State;
+use crate::algorithms::local_search::run_summary::RunSummary;
 use crate::algorithms::visualization::to_image::ToImage;
 use crate::boolean_formulae::clause::Clause;
 use crate::boolean_formulae::data::Sample;
 use crate::boolean_formulae::dnf::DNF;
+use crate::boolean_formulae::sample_store::SampleStore;
 
-mod regularizer;
-mod neighbourhood_generator;
-mod state;
+pub mod regularizer;
+pub mod moves;
+pub mod neighbourhood;
+pub mod neighbourhood_generator;
+pub mod neighbourhood_operator;
+pub mod operator_registry;
+pub mod operator_selection;
+pub mod state;
+pub mod termination_criteria;
+pub mod run_summary;
 pub mod run_config;
-mod algorithms;
+pub mod algorithms;
+
+pub use algorithms::search_algorithm::SearchAlgorithm;
+pub use algorithms::AlgorithmRunner;
+pub use neighbourhood::Neighbourhood;
+pub use neighbourhood_operator::NeighbourhoodOperator;
+pub use operator_registry::OperatorRegistry;
+
+/// Error Enum for setting up a local search from a run config.
+#[derive(Debug)]
+pub enum ErrorKind {
+	/// The image dimensions do not fit the dimension of the data.
+	/// Contains the width and height of the images and the dimension of the data.
+	WrongImageDimensions(u32, u32, usize),
+	/// The algorithm selects the neighbourhood operators by itself, but the config
+	/// selects them by another strategy.
+	/// Contains the algorithm and the operator selection.
+	ConflictingOperatorSelection(Algorithm, OperatorSelection),
+	/// The parameters of the algorithm are out of range.
+	/// Contains the algorithm.
+	InvalidAlgorithm(Algorithm),
+	/// The parameters of the operator selection are out of range for the number of
+	/// neighbourhood operators.
+	/// Contains the operator selection and the number of operators.
+	InvalidOperatorSelection(OperatorSelection, usize),
+	/// The images of a `RemovePatch` generator do not fit the dimension of the data.
+	/// Contains the width and height of the images and the dimension of the data.
+	WrongPatchDimensions(usize, usize, usize),
+	/// The images of a `RemovePatch` generator do not have the image dimensions of the
+	/// config.
+	/// Contains the width and height of the generator's and of the config's images.
+	ConflictingPatchDimensions(usize, usize, u32, u32),
+	/// The patches of a `RemovePatch` generator are empty or do not fit into its images.
+	/// Contains the side length of the patches and the width and height of the images.
+	InvalidPatchSize(usize, usize, usize),
+	/// The datafile does not contain any samples and the config does not set the
+	/// dimension of the data.
+	NoSamples,
+	/// A sample of the datafile is not of the dimension of the data.
+	/// Contains the dimension of the sample and the dimension of the data.
+	WrongSampleDimension(usize, usize),
+	/// A neighbourhood operator of the config can not be created by the registry.
+	/// Contains the error of the registry.
+	InvalidOperator(operator_registry::ErrorKind),
+	/// The datafile can not be read.
+	/// Contains the error of the read.
+	UnreadableData(std::io::Error),
+	/// The datafile can be read, but does not contain a list of samples.
+	/// Contains the error of the parser.
+	InvalidData(serde_json::Error),
+}
 
 /// A basic hill climber
 ///
+/// # Errors
+/// Will return an `ErrorKind` if the config is inconsistent or does not fit the data.
+pub fn local_search(run_config: &RunConfig) -> Result<(), ErrorKind> {
+	search_with_registry(run_config, &OperatorRegistry::default())
+}
+
+/// Runs the local search like `local_search`, resolving the neighbourhood operators of
+/// the config which are not built-in by the registry.
+///
+/// # Errors
+/// Will return an `ErrorKind` if the config is inconsistent or does not fit the data, or
+/// `ErrorKind::InvalidOperator` if an operator of the config can not be created by the
+/// registry.
+///
 /// # Panics
-pub fn local_search<const DATA_DIM: usize>(run_config: &RunConfig<DATA_DIM>)
-where
-	BitsImpl<DATA_DIM>: Bits,
-	<BitsImpl<{ DATA_DIM }> as Bits>::Store: Hash,
-{
-	// Instantiate components from run_config.
-	let regularizer = run_config.regularizer;
-	let neighbourhood_generators = run_config.neighbourhood_generators.clone();
+/// Panics if the output of the runs can not be written.
+pub fn search_with_registry(run_config: &RunConfig, registry: &OperatorRegistry) -> Result<(), ErrorKind> {
+	// Fail before writing any output if the config is inconsistent.
+	validate_config(run_config, registry)?;
 	let algorithm = run_config.algorithm;
+	let termination = run_config
+		.termination
+		.with_max_iterations(algorithm.max_iterations());
+	let seed = run_config.seed.unwrap_or_else(|| thread_rng().gen());
+	debug!("Seeding the runs with {}.", seed);
 
 	// Read data into memory.
-	let (positive_samples, negative_samples): (Vec<Sample<DATA_DIM>>, Vec<Sample<DATA_DIM>>) =
-		serde_json::from_str::<Vec<Sample<DATA_DIM>>>(
-			&fs::read_to_string(Path::new(&run_config.data_path))
-				.expect("Could not read from the provided datafile."),
-		)
-		.expect("The datafile could be read, but it contained an error and could not be parsed to Samples.")
-		.into_iter()
-		.partition(Sample::label);
+	let (samples, data_dimension) = read_samples(run_config)?;
+	let image_dimensions = check_dimensions(run_config, data_dimension)?;
+	let (positive_samples, negative_samples): (Vec<Sample>, Vec<Sample>) =
+		samples.into_iter().partition(Sample::label);
 	// Create starting DNFs from memory.
 	let positive_dnf = DNF::new(positive_samples.par_iter().map(Clause::from).collect());
 	let negative_dnf = DNF::new(negative_samples.par_iter().map(Clause::from).collect());
-	let initial_state: State<DATA_DIM> = State {
-		positive_dnf,
-		negative_dnf,
-	};
+	// Store the samples column-major for fast evaluation of whole DNFs.
+	let positive_store = SampleStore::new(data_dimension, &positive_samples);
+	let negative_store = SampleStore::new(data_dimension, &negative_samples);
+	let initial_state = State::new(positive_dnf, negative_dnf, &positive_store, &negative_store);
 
 	// Create general output-paths and write backs to easier reconstruct the run.
 	let output_dir = Path::new("output");
 	let current_time = Utc::now();
 	let run_dir = output_dir.join(format!("{}", current_time.format("%F-%T")));
-	let data_dir = run_dir.join("data");
-	create_dir_all(&run_dir).expect("Could not create output directory for run.");
-	create_dir_all(&data_dir).expect("Could not create dir to write back data");
-	for (index, sample) in positive_samples
+	write_back(
+		run_config,
+		&run_dir,
+		&positive_samples,
+		&negative_samples,
+		image_dimensions,
+	);
+
+	for current_run in 1..=run_config.run_count {
+		debug!("Starting run #{}", current_run);
+		let run_seed = seed.wrapping_add(u64::from(current_run - 1));
+		let algorithm_runner = AlgorithmRunner::new(
+			algorithm.into_search_algorithm(),
+			initial_state.clone(),
+			&positive_store,
+			&negative_store,
+			create_operators(run_config, registry)?,
+			run_config.regularizer,
+			termination,
+			run_seed,
+		)
+		.with_operator_selection(run_config.operator_selection)
+		.expect("The operator selection was checked against the algorithm and the operators.");
+		perform_run(
+			run_config,
+			algorithm_runner,
+			run_seed,
+			&run_dir.join(format!("run-{}", current_run)),
+			image_dimensions,
+		);
+	}
+	Ok(())
+}
+
+/// Creates the neighbourhood operators of the config, resolving those which are not
+/// built-in by the registry.
+fn create_operators(
+	run_config: &RunConfig,
+	registry: &OperatorRegistry,
+) -> Result<Vec<Box<dyn NeighbourhoodOperator>>, ErrorKind> {
+	run_config
+		.neighbourhood_generators
 		.iter()
-		.enumerate()
-		.chain(negative_samples.iter().enumerate())
+		.map(|config| registry.create(config).map_err(ErrorKind::InvalidOperator))
+		.collect()
+}
+
+/// Checks the operators, the algorithm and the operator selection of the config
+/// against each other.
+fn validate_config(run_config: &RunConfig, registry: &OperatorRegistry) -> Result<(), ErrorKind> {
+	create_operators(run_config, registry)?;
+	let algorithm = run_config.algorithm;
+	if !algorithm.is_valid() {
+		return Err(ErrorKind::InvalidAlgorithm(algorithm));
+	}
+	if algorithm.into_search_algorithm().selects_operators()
+		&& !matches!(run_config.operator_selection, OperatorSelection::All)
 	{
-		let label = if sample.label() { "positive" } else { "negative" };
-		sample
-			.to_image(28, 28)
-			.unwrap()
-			.save(data_dir.join(format!("{}-sample-{}.png", label, index + 1).as_str()))
-			.unwrap();
+		return Err(ErrorKind::ConflictingOperatorSelection(
+			algorithm,
+			run_config.operator_selection,
+		));
+	}
+	let operator_count = run_config.neighbourhood_generators.len();
+	if !run_config.operator_selection.is_valid(operator_count) {
+		return Err(ErrorKind::InvalidOperatorSelection(
+			run_config.operator_selection,
+			operator_count,
+		));
+	}
+	Ok(())
+}
+
+/// Reads the samples from the datafile of the config and returns them together with
+/// the dimension of the data.
+fn read_samples(run_config: &RunConfig) -> Result<(Vec<Sample>, usize), ErrorKind> {
+	let samples = serde_json::from_str::<Vec<Sample>>(
+		&fs::read_to_string(Path::new(&run_config.data_path)).map_err(ErrorKind::UnreadableData)?,
+	)
+	.map_err(ErrorKind::InvalidData)?;
+	let data_dimension = run_config
+		.data_dimension
+		.or_else(|| samples.first().map(Sample::dimension))
+		.ok_or(ErrorKind::NoSamples)?;
+	if let Some(sample) = samples.iter().find(|sample| sample.dimension() != data_dimension) {
+		return Err(ErrorKind::WrongSampleDimension(
+			sample.dimension(),
+			data_dimension,
+		));
+	}
+	debug!("Read {} samples of dimension {}.", samples.len(), data_dimension);
+	Ok((samples, data_dimension))
+}
+
+/// Checks the image dimensions of the config and of its `RemovePatch` generators
+/// against the dimension of the data and against each other, and the patches of the
+/// generators against their images.
+/// Returns the dimensions of the images to visualize the data with, if any.
+fn check_dimensions(run_config: &RunConfig, data_dimension: usize) -> Result<Option<(u32, u32)>, ErrorKind> {
+	let image_dimensions = match run_config.image_dimensions {
+		Some((width, height)) if width as usize * height as usize != data_dimension => {
+			return Err(ErrorKind::WrongImageDimensions(width, height, data_dimension));
+		},
+		Some(dimensions) => Some(dimensions),
+		None => square_image_dimensions(data_dimension),
+	};
+	for config in &run_config.neighbourhood_generators {
+		if let OperatorConfig::BuiltIn(NeighbourhoodGenerator::RemovePatch {
+			width,
+			height,
+			patch_size,
+			..
+		}) = *config
+		{
+			if width.checked_mul(height) != Some(data_dimension) {
+				return Err(ErrorKind::WrongPatchDimensions(width, height, data_dimension));
+			}
+			if let Some((image_width, image_height)) = run_config.image_dimensions {
+				if (width, height) != (image_width as usize, image_height as usize) {
+					return Err(ErrorKind::ConflictingPatchDimensions(
+						width,
+						height,
+						image_width,
+						image_height,
+					));
+				}
+			}
+			if patch_size == 0 || patch_size > width.min(height) {
+				return Err(ErrorKind::InvalidPatchSize(patch_size, width, height));
+			}
+		}
+	}
+	if image_dimensions.is_none() {
+		debug!(
+			"Data of dimension {} can not be visualized as square images.",
+			data_dimension
+		);
+	}
+	Ok(image_dimensions)
+}
+
+/// Creates the output directory of the run and writes back the samples and the config.
+///
+/// # Panics
+/// Panics if the output can not be written.
+fn write_back(
+	run_config: &RunConfig,
+	run_dir: &Path,
+	positive_samples: &[Sample],
+	negative_samples: &[Sample],
+	image_dimensions: Option<(u32, u32)>,
+) {
+	let data_dir = run_dir.join("data");
+	create_dir_all(run_dir).expect("Could not create output directory for run.");
+	create_dir_all(&data_dir).expect("Could not create dir to write back data");
+	if let Some((width, height)) = image_dimensions {
+		for (index, sample) in positive_samples
+			.iter()
+			.enumerate()
+			.chain(negative_samples.iter().enumerate())
+		{
+			let label = if sample.label() { "positive" } else { "negative" };
+			sample
+				.to_image(width, height)
+				.expect("The image dimensions were checked against the data.")
+				.save(data_dir.join(format!("{}-sample-{}.png", label, index + 1).as_str()))
+				.unwrap();
+		}
 	}
 	let mut config_write_back =
 		File::create(&run_dir.join("config.json")).expect("Could not create file to write back config to.");
 	config_write_back
 		.write_all(serde_json::to_string(&run_config).unwrap().as_bytes())
 		.expect("Could not write back config.");
+}
 
-	for current_run in 1..=run_config.run_count {
-		debug!("Starting run #{}", current_run);
-		// Create run-specific output directories and files.
-		let iteration_dir = run_dir.join(format!("run-{}", current_run));
-		create_dir_all(&iteration_dir)
-			.unwrap_or_else(|_| panic!("Could not create output directory for run {}.", current_run));
-		let metrics_dir = iteration_dir.join("metrics");
-		create_dir_all(&metrics_dir)
-			.unwrap_or_else(|_| panic!("Could not create metrics directory in run {}.", current_run));
-		let mut output_file =
-			File::create(&iteration_dir.join("best_state.json")).expect("Could not create output file.");
-		let mut metrics_file =
-			File::create(&metrics_dir.join("metrics.csv")).expect("Could not create metrics file.");
-
-		// Prepare tracking of current and best state.
-		let current_state = initial_state.clone();
-		let mut best_state = current_state.clone();
-
-		// Pre-Run metrics
-		if let Some(_metrics) = &run_config.metrics {
-			metrics_file
-				.write_all(b"Iteration,Elapsed-Time,Regularizer-Value\n")
-				.expect("Could not write to metrics file.");
-			save_metrics(
-				&mut metrics_file,
-				"0",
-				"0",
-				regularizer.regularize(&current_state).to_string().as_str(),
-			);
-			generate_pictures(&current_state, &metrics_dir, "0");
-		}
+/// Steps the algorithm runner until it terminates, and writes the metrics, the best
+/// state and the summary of the run to the given directory.
+///
+/// # Panics
+/// Panics if the output can not be written.
+fn perform_run(
+	run_config: &RunConfig,
+	mut algorithm_runner: AlgorithmRunner<'_>,
+	run_seed: u64,
+	iteration_dir: &Path,
+	image_dimensions: Option<(u32, u32)>,
+) {
+	let regularizer = run_config.regularizer;
+	// Create run-specific output directories and files.
+	create_dir_all(iteration_dir).expect("Could not create output directory for run.");
+	let metrics_dir = iteration_dir.join("metrics");
+	create_dir_all(&metrics_dir).expect("Could not create metrics directory in run.");
+	let mut output_file =
+		File::create(&iteration_dir.join("best_state.json")).expect("Could not create output file.");
+	let mut metrics_file =
+		File::create(&metrics_dir.join("metrics.csv")).expect("Could not create metrics file.");
 
-		let mut algorithm_runner = AlgorithmRunner::new(
-			algorithm,
-			current_state,
-			positive_samples.as_slice(),
-			negative_samples.as_slice(),
-			neighbourhood_generators.clone(),
-			regularizer,
+	// Prepare tracking of the best state.
+	let mut best_state = algorithm_runner.current_state().clone();
+
+	// Pre-Run metrics
+	if let Some(_metrics) = &run_config.metrics {
+		metrics_file
+			.write_all(b"Iteration,Elapsed-Time,Regularizer-Value\n")
+			.expect("Could not write to metrics file.");
+		save_metrics(
+			&mut metrics_file,
+			"0",
+			"0",
+			regularizer.regularize(&best_state).to_string().as_str(),
 		);
+		generate_pictures(&best_state, &metrics_dir, "0", image_dimensions);
+	}
 
-		let mut iteration_time = Utc::now();
-
-		while let Some(current_state) = algorithm_runner.step() {
-			debug!("In Iteration {}", algorithm_runner.iteration());
-			if let Some(metrics) = &run_config.metrics {
-				let iteration = algorithm_runner.iteration();
-				if iteration % metrics.regularizer_frequency == 0 {
-					let current_time = Utc::now();
-					let difference = current_time - iteration_time;
-					save_metrics(
-						&mut metrics_file,
-						iteration.to_string().as_str(),
-						format!("{}.{}", difference.num_seconds(), difference.num_milliseconds()).as_str(),
-						regularizer.regularize(&current_state).to_string().as_str(),
-					);
-					iteration_time = current_time;
-				}
-				if iteration % metrics.picture_frequency == 0 {
-					generate_pictures(&current_state, &metrics_dir, iteration.to_string().as_str());
-				}
-			}
+	let run_start = Instant::now();
+	let mut iteration_time = Utc::now();
 
-			if regularizer.regularize(&current_state) < regularizer.regularize(&best_state) {
-				best_state = current_state.clone();
+	while algorithm_runner.step() {
+		debug!("In Iteration {}", algorithm_runner.iteration());
+		let current_state = algorithm_runner.current_state();
+		if let Some(metrics) = &run_config.metrics {
+			let iteration = algorithm_runner.iteration();
+			if iteration % metrics.regularizer_frequency == 0 {
+				let current_time = Utc::now();
+				let difference = current_time - iteration_time;
+				save_metrics(
+					&mut metrics_file,
+					iteration.to_string().as_str(),
+					format!("{}.{}", difference.num_seconds(), difference.num_milliseconds()).as_str(),
+					regularizer.regularize(current_state).to_string().as_str(),
+				);
+				iteration_time = current_time;
+			}
+			if iteration % metrics.picture_frequency == 0 {
+				generate_pictures(
+					current_state,
+					&metrics_dir,
+					iteration.to_string().as_str(),
+					image_dimensions,
+				);
 			}
 		}
 
-		if let Some(_metrics) = &run_config.metrics {
-			let iteration = algorithm_runner.iteration().to_string();
-			let current_time = Utc::now();
-			let difference = current_time - iteration_time;
-			save_metrics(
-				&mut metrics_file,
-				&iteration,
-				format!("{}.{}", difference.num_seconds(), difference.num_milliseconds()).as_str(),
-				regularizer.regularize(&best_state).to_string().as_str(),
-			);
-			generate_pictures(&best_state, &metrics_dir, &iteration);
+		if regularizer.regularize(current_state) < regularizer.regularize(&best_state) {
+			best_state = current_state.clone();
 		}
+	}
 
-		output_file
-			.write_all(serde_json::to_string(&best_state).unwrap().as_bytes())
-			.expect("Could not write final state to output file.");
+	if let Some(_metrics) = &run_config.metrics {
+		let iteration = algorithm_runner.iteration().to_string();
+		let current_time = Utc::now();
+		let difference = current_time - iteration_time;
+		save_metrics(
+			&mut metrics_file,
+			&iteration,
+			format!("{}.{}", difference.num_seconds(), difference.num_milliseconds()).as_str(),
+			regularizer.regularize(&best_state).to_string().as_str(),
+		);
+		generate_pictures(&best_state, &metrics_dir, &iteration, image_dimensions);
 	}
+
+	output_file
+		.write_all(serde_json::to_string(&best_state).unwrap().as_bytes())
+		.expect("Could not write final state to output file.");
+
+	let summary = RunSummary {
+		seed:                    run_seed,
+		termination_reason:      algorithm_runner.termination_reason(),
+		iterations:              algorithm_runner.iteration(),
+		feasibility_evaluations: algorithm_runner.feasibility_evaluations(),
+		best_value:              regularizer.regularize(&best_state),
+		elapsed_seconds:         run_start.elapsed().as_secs_f64(),
+		operator_statistics:     algorithm_runner.operator_statistics().map(<[_]>::to_vec),
+	};
+	let mut summary_file =
+		File::create(&iteration_dir.join("summary.json")).expect("Could not create summary file.");
+	summary_file
+		.write_all(serde_json::to_string(&summary).unwrap().as_bytes())
+		.expect("Could not write summary to summary file.");
 }
 
 /// Creates Visualizations of the current state and saves them under the provided path
 /// with filenames distinguished by the current iteration.
-fn generate_pictures<const SIZE: usize>(state: &State<SIZE>, path: &Path, label: &str)
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	state
-		.positive_dnf
-		.to_image(28, 28)
-		.unwrap()
-		.save(path.join(format!("iteration-{}-positive.png", label).as_str()))
-		.unwrap();
-	state
-		.negative_dnf
-		.to_image(28, 28)
-		.unwrap()
-		.save(path.join(format!("iteration-{}-negative.png", label).as_str()))
-		.unwrap();
+/// Does nothing if no image dimensions are known.
+///
+/// # Panics
+/// Panics if the image dimensions do not fit the dimension of the state.
+fn generate_pictures(state: &State, path: &Path, label: &str, image_dimensions: Option<(u32, u32)>) {
+	if let Some((width, height)) = image_dimensions {
+		state
+			.positive_dnf
+			.to_image(width, height)
+			.expect("The image dimensions were checked against the data.")
+			.save(path.join(format!("iteration-{}-positive.png", label).as_str()))
+			.unwrap();
+		state
+			.negative_dnf
+			.to_image(width, height)
+			.expect("The image dimensions were checked against the data.")
+			.save(path.join(format!("iteration-{}-negative.png", label).as_str()))
+			.unwrap();
+	}
+}
+
+/// Returns the dimensions of a square image with exactly `data_dimension` pixels,
+/// or `None` if there is no such image or the data is empty.
+fn square_image_dimensions(data_dimension: usize) -> Option<(u32, u32)> {
+	if data_dimension == 0 {
+		return None;
+	}
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_precision_loss,
+		clippy::cast_sign_loss
+	)]
+	let side = (data_dimension as f64).sqrt().round() as u32;
+	(side as usize * side as usize == data_dimension).then(|| (side, side))
 }
 
 /// Writes metrics generated by the regularizer to the metrics file.
-fn save_metrics<const SIZE: usize>(
-	metrics_file: &mut File,
-	iteration: &str,
-	elapsed_time: &str,
-	regularization: &str,
-) where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+fn save_metrics(metrics_file: &mut File, iteration: &str, elapsed_time: &str, regularization: &str) {
 	metrics_file
-		.write_all(format!("{},{},{}\n", iteration, elapsed_time, regularization,).as_bytes())
+		.write_all(format!("{},{},{}\n", iteration, elapsed_time, regularization).as_bytes())
 		.expect("Could not write to the metrics file.");
 }
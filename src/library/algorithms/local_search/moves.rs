@@ -0,0 +1,375 @@
+//! Provides moves, i.e. descriptions of how to get from a state to one of its neighbours
+//! without constructing the neighbour.
+
+use std::collections::{BTreeSet,};
+
+use crate::algorithms::local_search::state::State;
+use crate::boolean_formulae::clause::Clause;
+use crate::boolean_formulae::data::FeatureID;
+
+/// A literal of one of the `DNF`s of a state, independent of the clause it appears in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Attribute {
+	/// Whether the literal appears in the positive `DNF`.
+	pub which_dnf:  bool,
+	/// The feature of the literal.
+	pub feature_id: FeatureID,
+	/// The parity of the literal.
+	pub parity:     bool,
+}
+
+/// A change of one `DNF` of a state, as generated by a `NeighbourhoodOperator`.
+#[derive(Debug, Clone)]
+pub enum Move {
+	/// Removes the literal of a feature from one clause.
+	RemoveLiteral {
+		/// Whether the clause is part of the positive `DNF`.
+		which_dnf:  bool,
+		/// The clause to remove the literal from.
+		clause:     Clause,
+		/// The feature whose literal is removed.
+		feature_id: FeatureID,
+	},
+	/// Removes the literals of several features from one clause.
+	RemoveLiterals {
+		/// Whether the clause is part of the positive `DNF`.
+		which_dnf:   bool,
+		/// The clause to remove the literals from.
+		clause:      Clause,
+		/// The features whose literals are removed.
+		feature_ids: Vec<FeatureID>,
+	},
+	/// Inserts a literal into one clause.
+	InsertLiteral {
+		/// Whether the clause is part of the positive `DNF`.
+		which_dnf:  bool,
+		/// The clause to insert the literal into.
+		clause:     Clause,
+		/// The feature of the literal.
+		feature_id: FeatureID,
+		/// The parity of the inserted literal.
+		parity:     bool,
+	},
+	/// Removes the literals of a feature from all clauses of a `DNF`.
+	RemoveFromAllClauses {
+		/// Whether to modify the positive `DNF`.
+		which_dnf:  bool,
+		/// The feature whose literals are removed.
+		feature_id: FeatureID,
+	},
+	/// Inserts a literal into one clause and removes the literals of its feature from all
+	/// other clauses of the `DNF`.
+	InsertOneRemoveElsewhere {
+		/// Whether to modify the positive `DNF`.
+		which_dnf:  bool,
+		/// The clause to insert the literal into.
+		clause:     Clause,
+		/// The feature of the literal.
+		feature_id: FeatureID,
+		/// The parity of the inserted literal.
+		parity:     bool,
+	},
+	/// Removes one clause of a `DNF`.
+	RemoveClause {
+		/// Whether the clause is part of the positive `DNF`.
+		which_dnf: bool,
+		/// The clause to remove.
+		clause:    Clause,
+	},
+	/// Replaces two clauses of a `DNF` by their least general generalization.
+	MergeClauses {
+		/// Whether to modify the positive `DNF`.
+		which_dnf: bool,
+		/// The first clause to merge.
+		first:     Clause,
+		/// The second clause to merge.
+		second:    Clause,
+	},
+	/// Replaces clauses of a `DNF` by other clauses, e.g. for operators which are not
+	/// described by one of the other moves.
+	ReplaceClauses {
+		/// Whether to modify the positive `DNF`.
+		which_dnf: bool,
+		/// The clauses to remove.
+		removed:   Vec<Clause>,
+		/// The clauses to insert.
+		inserted:  Vec<Clause>,
+	},
+}
+
+impl Move {
+	/// Returns the clauses this move removes from and inserts into the state.
+	#[must_use]
+	pub fn modification(&self, state: &State) -> Modification {
+		match self {
+			Self::RemoveLiteral {
+				which_dnf,
+				clause,
+				feature_id,
+			} => {
+				let mut modified = clause.clone();
+				modified.remove_literal(*feature_id);
+				Modification::new(state, *which_dnf, vec![clause.clone()], vec![modified])
+			},
+			Self::RemoveLiterals {
+				which_dnf,
+				clause,
+				feature_ids,
+			} => {
+				let mut modified = clause.clone();
+				for feature_id in feature_ids {
+					modified.remove_literal(*feature_id);
+				}
+				Modification::new(state, *which_dnf, vec![clause.clone()], vec![modified])
+			},
+			Self::InsertLiteral {
+				which_dnf,
+				clause,
+				feature_id,
+				parity,
+			} => {
+				let mut modified = clause.clone();
+				modified.insert_literal(*feature_id, *parity);
+				Modification::new(state, *which_dnf, vec![clause.clone()], vec![modified])
+			},
+			Self::RemoveFromAllClauses {
+				which_dnf,
+				feature_id,
+			} => {
+				let (removed, inserted) = state
+					.dnf(*which_dnf)
+					.clauses()
+					.iter()
+					.filter(|clause| clause.literal_at(*feature_id).is_some())
+					.map(|clause| {
+						let mut modified = clause.clone();
+						modified.remove_literal(*feature_id);
+						(clause.clone(), modified)
+					})
+					.unzip();
+				Modification::new(state, *which_dnf, removed, inserted)
+			},
+			Self::InsertOneRemoveElsewhere {
+				which_dnf,
+				clause,
+				feature_id,
+				parity,
+			} => {
+				let (mut removed, mut inserted): (Vec<Clause>, Vec<Clause>) = state
+					.dnf(*which_dnf)
+					.clauses()
+					.iter()
+					.filter(|other| other.literal_at(*feature_id).is_some())
+					.map(|other| {
+						let mut modified = other.clone();
+						modified.remove_literal(*feature_id);
+						(other.clone(), modified)
+					})
+					.unzip();
+				let mut modified = clause.clone();
+				modified.insert_literal(*feature_id, *parity);
+				removed.push(clause.clone());
+				inserted.push(modified);
+				Modification::new(state, *which_dnf, removed, inserted)
+			},
+			Self::RemoveClause { which_dnf, clause } => {
+				Modification::new(state, *which_dnf, vec![clause.clone()], Vec::new())
+			},
+			Self::MergeClauses {
+				which_dnf,
+				first,
+				second,
+			} => Modification::new(
+				state,
+				*which_dnf,
+				vec![first.clone(), second.clone()],
+				vec![first.generalization(second)],
+			),
+			Self::ReplaceClauses {
+				which_dnf,
+				removed,
+				inserted,
+			} => Modification::new(state, *which_dnf, removed.clone(), inserted.clone()),
+		}
+	}
+
+	/// Returns the literals inserted or removed by this move.
+	#[must_use]
+	pub fn attributes(&self, state: &State) -> Vec<Attribute> {
+		match self {
+			Self::RemoveLiteral {
+				which_dnf,
+				clause,
+				feature_id,
+			} => clause
+				.literal_at(*feature_id)
+				.map(|parity| Attribute {
+					which_dnf: *which_dnf,
+					feature_id: *feature_id,
+					parity,
+				})
+				.into_iter()
+				.collect(),
+			Self::RemoveLiterals {
+				which_dnf,
+				clause,
+				feature_ids,
+			} => feature_ids
+				.iter()
+				.filter_map(|feature_id| {
+					clause.literal_at(*feature_id).map(|parity| Attribute {
+						which_dnf: *which_dnf,
+						feature_id: *feature_id,
+						parity,
+					})
+				})
+				.collect(),
+			Self::InsertLiteral {
+				which_dnf,
+				feature_id,
+				parity,
+				..
+			} => vec![Attribute {
+				which_dnf:  *which_dnf,
+				feature_id: *feature_id,
+				parity:     *parity,
+			}],
+			Self::RemoveFromAllClauses {
+				which_dnf,
+				feature_id,
+			} => Self::present_attributes(state, *which_dnf, *feature_id),
+			Self::InsertOneRemoveElsewhere {
+				which_dnf,
+				feature_id,
+				parity,
+				..
+			} => {
+				let inserted = Attribute {
+					which_dnf:  *which_dnf,
+					feature_id: *feature_id,
+					parity:     *parity,
+				};
+				let mut attributes = Self::present_attributes(state, *which_dnf, *feature_id);
+				if !attributes.contains(&inserted) {
+					attributes.push(inserted);
+				}
+				attributes
+			},
+			Self::RemoveClause { which_dnf, clause } => {
+				Self::changed_attributes(*which_dnf, std::slice::from_ref(clause), &[])
+			},
+			Self::MergeClauses {
+				which_dnf,
+				first,
+				second,
+			} => Self::changed_attributes(
+				*which_dnf,
+				&[first.clone(), second.clone()],
+				&[first.generalization(second)],
+			),
+			Self::ReplaceClauses {
+				which_dnf,
+				removed,
+				inserted,
+			} => Self::changed_attributes(*which_dnf, removed, inserted),
+		}
+	}
+
+	/// Returns the literals appearing in only the removed or only the inserted clauses.
+	fn changed_attributes(which_dnf: bool, removed: &[Clause], inserted: &[Clause]) -> Vec<Attribute> {
+		let literals = |clauses: &[Clause]| {
+			clauses
+				.iter()
+				.flat_map(|clause| {
+					clause.appearances().iter().map(move |feature_id| Attribute {
+						which_dnf,
+						feature_id,
+						parity: clause.literal_at(feature_id) == Some(true),
+					})
+				})
+				.collect::<BTreeSet<Attribute>>()
+		};
+		literals(removed)
+			.symmetric_difference(&literals(inserted))
+			.copied()
+			.collect()
+	}
+
+	/// Returns the literals of a feature present in one of the `DNF`s of the state.
+	fn present_attributes(state: &State, which_dnf: bool, feature_id: FeatureID) -> Vec<Attribute> {
+		[true, false]
+			.into_iter()
+			.filter(|parity| {
+				state
+					.dnf(which_dnf)
+					.clauses()
+					.iter()
+					.any(|clause| clause.literal_at(feature_id) == Some(*parity))
+			})
+			.map(|parity| Attribute {
+				which_dnf,
+				feature_id,
+				parity,
+			})
+			.collect()
+	}
+}
+
+/// The effect of a move on one `DNF` of a state, i.e. which of its clauses are removed
+/// and which are inserted.
+///
+/// Applying the modification yields the `DNF` consisting of the not removed and the
+/// inserted clauses. Empty clauses are never inserted, since they do not cover any
+/// sample.
+#[derive(Debug, Clone)]
+pub struct Modification {
+	/// Whether the positive `DNF` is modified.
+	which_dnf: bool,
+	/// The clauses of the `DNF` which are removed.
+	removed:   Vec<Clause>,
+	/// The clauses not yet in the `DNF` which are inserted.
+	inserted:  Vec<Clause>,
+}
+
+impl Modification {
+	/// Creates a new modification of the state's `DNF` selected by `which_dnf`.
+	///
+	/// Clauses which are both removed and inserted are kept,
+	/// as are clauses which are inserted while already being present.
+	#[must_use]
+	pub fn new(state: &State, which_dnf: bool, removed: Vec<Clause>, inserted: Vec<Clause>) -> Self {
+		let present = state.dnf(which_dnf).clauses();
+		let inserted: BTreeSet<Clause> = inserted.into_iter().filter(|clause| !clause.is_empty()).collect();
+		let removed = removed
+			.into_iter()
+			.collect::<BTreeSet<Clause>>()
+			.into_iter()
+			.filter(|clause| present.contains(clause) && !inserted.contains(clause))
+			.collect();
+		let inserted = inserted
+			.into_iter()
+			.filter(|clause| !present.contains(clause))
+			.collect();
+		Self {
+			which_dnf,
+			removed,
+			inserted,
+		}
+	}
+
+	/// Whether the positive `DNF` is modified.
+	#[must_use]
+	pub const fn which_dnf(&self) -> bool { self.which_dnf }
+
+	/// The clauses removed from the `DNF`.
+	#[must_use]
+	pub fn removed(&self) -> &[Clause] { &self.removed }
+
+	/// The clauses inserted into the `DNF`.
+	#[must_use]
+	pub fn inserted(&self) -> &[Clause] { &self.inserted }
+
+	/// Splits the modification into the removed and the inserted clauses.
+	#[must_use]
+	pub fn into_parts(self) -> (Vec<Clause>, Vec<Clause>) { (self.removed, self.inserted) }
+}
@@ -0,0 +1,204 @@
+//! Provides the neighbourhood of states which the search algorithms explore.
+
+use std::sync::atomic::{
+	AtomicU64,
+	Ordering,
+};
+use log::{trace,};
+use rand::rngs::StdRng;
+use rand::{
+	Rng,
+	SeedableRng,
+};
+use rayon::prelude::*;
+
+use crate::algorithms::local_search::moves::{
+	Modification,
+	Move,
+};
+use crate::algorithms::local_search::neighbourhood_operator::NeighbourhoodOperator;
+use crate::algorithms::local_search::regularizer::Regularizer;
+use crate::algorithms::local_search::state::State;
+use crate::boolean_formulae::sample_store::SampleStore;
+
+/// The neighbours of states generated by the neighbourhood operators, of which only
+/// those are feasible whose `DNF`s are exact w.r.t. the samples.
+#[derive(Debug)]
+pub struct Neighbourhood<'a> {
+	/// By which operators to generate new neighbours.
+	operators:               Vec<Box<dyn NeighbourhoodOperator>>,
+	/// All samples for which the positive DNF must be exact.
+	positive_samples:        &'a SampleStore,
+	/// All samples for which the negative DNF must be exact.
+	negative_samples:        &'a SampleStore,
+	/// How many neighbours were evaluated for feasibility.
+	feasibility_evaluations: AtomicU64,
+	/// The only operator to evaluate in the current step, if one was selected.
+	selected:                Option<usize>,
+}
+
+impl<'a> Neighbourhood<'a> {
+	/// Creates the neighbourhood generated by the operators w.r.t. the samples.
+	#[must_use]
+	pub const fn new(
+		operators: Vec<Box<dyn NeighbourhoodOperator>>,
+		positive_samples: &'a SampleStore,
+		negative_samples: &'a SampleStore,
+	) -> Self {
+		Self {
+			operators,
+			positive_samples,
+			negative_samples,
+			feasibility_evaluations: AtomicU64::new(0),
+			selected: None,
+		}
+	}
+
+	/// Returns the neighbourhood operators to evaluate in the current step, i.e. the
+	/// selected one if any, and all configured ones otherwise.
+	pub fn operators(&self) -> &[Box<dyn NeighbourhoodOperator>] {
+		self.selected.map_or(&self.operators, |index| {
+			std::slice::from_ref(&self.operators[index])
+		})
+	}
+
+	/// Restricts the following steps to the operator with the given index, or lifts the
+	/// restriction if `None`.
+	pub(crate) fn select(&mut self, selected: Option<usize>) { self.selected = selected; }
+
+	/// Returns all samples for which the positive DNF must be exact.
+	pub const fn positive_samples(&self) -> &'a SampleStore { self.positive_samples }
+
+	/// Returns all samples for which the negative DNF must be exact.
+	pub const fn negative_samples(&self) -> &'a SampleStore { self.negative_samples }
+
+	/// Returns how many neighbours were evaluated for feasibility so far.
+	pub fn feasibility_evaluations(&self) -> u64 { self.feasibility_evaluations.load(Ordering::Relaxed) }
+
+	/// Whether the state is still feasible after applying the modification, counting the
+	/// evaluation.
+	pub fn is_feasible_after(&self, state: &State, modification: &Modification) -> bool {
+		self.feasibility_evaluations.fetch_add(1, Ordering::Relaxed);
+		state.is_feasible_after(modification, self.positive_samples, self.negative_samples)
+	}
+
+	/// Moves the state to its neighbour described by the modification.
+	pub fn apply(&self, state: &mut State, modification: Modification) {
+		state.apply(modification, self.positive_samples, self.negative_samples);
+	}
+
+	/// Returns the modifications to all feasible neighbours of the state generated by the
+	/// operators.
+	pub fn feasible<'b>(
+		&'b self,
+		state: &'b State,
+		operators: &'b [Box<dyn NeighbourhoodOperator>],
+		rng: &mut StdRng,
+	) -> impl ParallelIterator<Item = Modification> + 'b {
+		trace!("Start generating neighbourhood.");
+		self.moves(state, operators, rng)
+			.map(|neighbour| neighbour.modification(state))
+			.filter(|modification| self.is_feasible_after(state, modification))
+	}
+
+	/// Returns the modification to the best feasible neighbour of the state generated by
+	/// the operators, if it improves the regularizer value.
+	pub fn best_improvement(
+		&self,
+		state: &State,
+		operators: &[Box<dyn NeighbourhoodOperator>],
+		regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> Option<Modification> {
+		let current_value = regularizer.regularize(state);
+		self.feasible(state, operators, rng)
+			.map(|modification| (regularizer.regularize_after(state, &modification), modification))
+			.min_by(|(first, _), (second, _)| first.total_cmp(second))
+			.and_then(|(value, modification)| (value < current_value).then(|| modification))
+	}
+
+	/// Moves the state to its best feasible neighbour generated by the current
+	/// operators, if it improves the regularizer value.
+	/// Returns whether the state was improved.
+	pub fn improve(&self, state: &mut State, regularizer: Regularizer, rng: &mut StdRng) -> bool {
+		self.best_improvement(state, self.operators(), regularizer, rng)
+			.map_or(false, |modification| {
+				self.apply(state, modification);
+				true
+			})
+	}
+
+	/// Proposes a random feasible neighbour of the state, generated by one of the
+	/// operators.
+	/// Draws the moves of all operators uniformly at random without replacement and
+	/// evaluates their feasibility until one is feasible, so each feasible neighbour is
+	/// equally likely to be proposed, regardless of whether the operators shuffle. The
+	/// number of feasibility evaluations only depends on `rng`.
+	/// Returns `None` if the state has no such feasible neighbour.
+	pub fn random_feasible(
+		&self,
+		state: &State,
+		operators: &[Box<dyn NeighbourhoodOperator>],
+		rng: &mut StdRng,
+	) -> Option<Modification> {
+		let mut moves: Vec<Move> = operators
+			.iter()
+			.zip(draw_seeds(rng, operators.len()))
+			.flat_map(|(operator, seed)| {
+				operator.generate_neighbourhood(
+					state,
+					self.positive_samples,
+					self.negative_samples,
+					&mut StdRng::seed_from_u64(seed),
+				)
+			})
+			.collect();
+		while !moves.is_empty() {
+			let modification = moves
+				.swap_remove(rng.gen_range(0..moves.len()))
+				.modification(state);
+			if self.is_feasible_after(state, &modification) {
+				return Some(modification);
+			}
+		}
+		None
+	}
+
+	/// Moves the state to random feasible neighbours generated by the current
+	/// operators, up to `steps` times.
+	pub fn mutate(&self, state: &mut State, steps: u32, rng: &mut StdRng) {
+		for _ in 0..steps {
+			if let Some(modification) = self.random_feasible(state, self.operators(), rng) {
+				self.apply(state, modification);
+			} else {
+				break;
+			}
+		}
+	}
+
+	/// Generates the moves to the neighbourhood of the state by all operators in
+	/// parallel. Each operator draws its randomness from its own seed drawn from `rng`,
+	/// s.t. the order of the moves does not depend on the scheduling of the threads.
+	pub fn moves<'b>(
+		&'b self,
+		state: &'b State,
+		operators: &'b [Box<dyn NeighbourhoodOperator>],
+		rng: &mut StdRng,
+	) -> impl ParallelIterator<Item = Move> + 'b {
+		let (positive_samples, negative_samples) = (self.positive_samples, self.negative_samples);
+		operators
+			.par_iter()
+			.zip(draw_seeds(rng, operators.len()))
+			.flat_map_iter(move |(operator, seed)| {
+				operator.generate_neighbourhood(
+					state,
+					positive_samples,
+					negative_samples,
+					&mut StdRng::seed_from_u64(seed),
+				)
+			})
+	}
+}
+
+/// Draws `count` seeds for independent random number generators of parallel tasks.
+pub fn draw_seeds<R: Rng>(rng: &mut R, count: usize) -> Vec<u64> { (0..count).map(|_| rng.gen()).collect() }
@@ -1,28 +1,35 @@
 //! Provides neighbourhood generation methods for run state.
 
-use std::hash::Hash;
-use bitmaps::{
-	Bitmap,
-	Bits,
-	BitsImpl,
-};
+use std::num::NonZeroUsize;
 use log::{trace,};
-use rand::prelude::{SliceRandom,};
-use rand::{
-	Rng,
-	thread_rng,
+use rand::prelude::{
+	IteratorRandom,
+	SliceRandom,
 };
+use rand::seq::index;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
 
 use serde::{
 	Serialize,
 	Deserialize,
 };
+use crate::algorithms::local_search::moves::Move;
+use crate::algorithms::local_search::neighbourhood::draw_seeds;
+use crate::algorithms::local_search::neighbourhood_operator::NeighbourhoodOperator;
 use crate::algorithms::local_search::state::State;
+use crate::boolean_formulae::bitset::Bitset;
 use crate::boolean_formulae::clause::Clause;
-use crate::boolean_formulae::data::FeatureID;
-use crate::boolean_formulae::dnf::DNF;
+use crate::boolean_formulae::data::{
+	FeatureID,
+	Sample,
+};
+use crate::boolean_formulae::evaluation::Cover;
+use crate::boolean_formulae::sample_store::SampleStore;
 
-/// Distinguishes different methods for generating Neighbourhoods of a `DNF`.
+/// Distinguishes the built-in methods for generating Neighbourhoods of a `DNF`.
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
 pub enum NeighbourhoodGenerator {
 	/// Remove one literal.
@@ -40,7 +47,8 @@ pub enum NeighbourhoodGenerator {
 		/// same polarity in all clauses of the dnf.
 		only_same_polarities: bool,
 	},
-	/// Insert a literal into one clause and remove it from all others.
+	/// Insert a literal into one random clause it is not present in and remove it from
+	/// all others.
 	InsertOneRemoveElsewhere,
 	/// Inserts one literal into a clause.
 	InsertOneLiteral {
@@ -51,174 +59,637 @@ pub enum NeighbourhoodGenerator {
 		/// you most likely want this to be `True`.
 		shuffle:             bool,
 	},
+	/// Removes one literal which is not blocking w.r.t. the samples of the opposite
+	/// class, i.e. which is not the only literal excluding one of them from its clause.
+	/// Thus, all generated neighbours are feasible.
+	RemoveNonBlockingLiteral {
+		/// Truncates the generated neighbourhood to the provided size.
+		neighbourhood_limit: Option<usize>,
+		/// Shuffles the generated neighbourhood,
+		/// introduces randomness,
+		/// you most likely want this to be `True`.
+		shuffle:             bool,
+	},
+	/// Removes all literals of a square patch of pixels from one clause, where the
+	/// features are the pixels of images of the given dimensions in row-major order.
+	RemovePatch {
+		/// The width of the images.
+		width:               usize,
+		/// The height of the images.
+		height:              usize,
+		/// The side length of the patches.
+		patch_size:          usize,
+		/// Truncates the generated neighbourhood to the provided size.
+		neighbourhood_limit: Option<usize>,
+		/// Shuffles the generated neighbourhood,
+		/// introduces randomness,
+		/// you most likely want this to be `True`.
+		shuffle:             bool,
+	},
+	/// Removes one clause whose covered samples are all covered by other clauses.
+	RemoveRedundantClause,
+	/// Ruins a `DNF` by removing some of its clauses and recreates it by greedily
+	/// covering the samples thereby uncovered by maximally generalized clauses, i.e. by
+	/// clauses of which no literal can be removed without covering samples of the
+	/// opposite class.
+	RuinAndRecreate {
+		/// How many randomly chosen clauses to remove, must not be zero.
+		ruined_clauses:     NonZeroUsize,
+		/// How many clauses to choose from in each greedy step, each generalizing another
+		/// randomly chosen uncovered sample.
+		candidates:         usize,
+		/// How many neighbours to generate.
+		neighbourhood_size: usize,
+	},
+	/// Replaces two clauses of a `DNF` by their least general generalization, i.e. the
+	/// clause of the literals both share with equal polarity.
+	MergeClauses {
+		/// Truncates the generated neighbourhood to the provided size.
+		neighbourhood_limit: Option<usize>,
+		/// Shuffles the generated neighbourhood,
+		/// introduces randomness,
+		/// you most likely want this to be `True`.
+		shuffle:             bool,
+	},
 }
 
 impl NeighbourhoodGenerator {
-	/// Helper for creating the resulting state after cloning and modifying a dnf from an
-	/// original state. Useful because in neighbourhood generation it does seldom matter
-	/// which dnf was modified. By using the state.dnfs() we can just iterate over them
-	/// and distinguish the creation of the new state here.
-	fn create_modified_state<const SIZE: usize>(
-		original_state: &State<SIZE>,
-		modified_dnf: DNF<SIZE>,
-		which_dnf: bool,
-	) -> State<SIZE>
-	where
-		BitsImpl<SIZE>: Bits,
-		<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-	{
-		if which_dnf {
-			State {
-				positive_dnf: modified_dnf,
-				negative_dnf: original_state.negative_dnf.clone(),
-			}
-		} else {
-			State {
-				positive_dnf: original_state.positive_dnf.clone(),
-				negative_dnf: modified_dnf,
-			}
-		}
-	}
+	/// The names by which the generators are referenced in the run config.
+	pub const NAMES: [&'static str; 9] = [
+		"RemoveOneLiteral",
+		"RemoveFromAllClauses",
+		"InsertOneRemoveElsewhere",
+		"InsertOneLiteral",
+		"RemoveNonBlockingLiteral",
+		"RemovePatch",
+		"RemoveRedundantClause",
+		"RuinAndRecreate",
+		"MergeClauses",
+	];
+}
 
-	/// Generates the neighbourhood of the `DNF` according to the generator strategy.
-	#[allow(clippy::too_many_lines)]
-	pub fn generate_neighbourhood<const SIZE: usize>(&self, state: &State<SIZE>) -> Vec<State<SIZE>>
-	where
-		BitsImpl<SIZE>: Bits,
-		<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-	{
-		let mut result: Vec<State<SIZE>> = Vec::new();
+impl NeighbourhoodOperator for NeighbourhoodGenerator {
+	fn generate_neighbourhood<'a>(
+		&self,
+		state: &'a State,
+		positive_samples: &'a SampleStore,
+		negative_samples: &'a SampleStore,
+		rng: &mut StdRng,
+	) -> Box<dyn Iterator<Item = Move> + 'a> {
 		match self {
 			Self::RemoveOneLiteral {
 				neighbourhood_limit,
 				shuffle,
-			} => {
-				let mut combinations: Vec<((&DNF<SIZE>, bool), Clause<SIZE>, FeatureID)> = state
-					.dnfs()
-					.iter()
-					.flat_map(|(dnf, which_dnf)| {
-						dnf.clauses().iter().flat_map(|clause| {
-							clause
-								.literal_indices()
-								.iter()
-								.map(|index| ((*dnf, *which_dnf), *clause, *index))
-								.collect::<Vec<((&DNF<SIZE>, bool), Clause<SIZE>, FeatureID)>>()
-						})
-					})
-					.collect();
-
-				if *shuffle {
-					combinations.shuffle(&mut thread_rng());
-				}
-
-				if let Some(limit) = neighbourhood_limit {
-					combinations = combinations
-						.choose_multiple(&mut thread_rng(), *limit)
-						.copied()
-						.collect::<Vec<((&DNF<SIZE>, bool), Clause<SIZE>, FeatureID)>>();
-				}
-
-				for ((dnf, which_dnf), mut clause, present_id) in combinations {
-					// Remove the original clause from the DNF …
-					let mut cloned_dnf = dnf.clone();
-					cloned_dnf.remove_clause(&clause);
-					// … modify it …
-					clause.remove_literal(present_id);
-					// … and re-inject it.
-					cloned_dnf.insert_clause(clause);
-
-					// store the result.
-					let modified_state = Self::create_modified_state(state, cloned_dnf, which_dnf);
-
-					result.push(modified_state);
-				}
-				trace!("Found {} neighbours by RemoveOneLiteral.", result.len());
-			},
+			} => Box::new(
+				clause_options(
+					state,
+					Clause::length,
+					|clause| clause.appearances().iter().collect(),
+					*neighbourhood_limit,
+					*shuffle,
+					rng,
+				)
+				.map(|(which_dnf, clause, feature_id)| Move::RemoveLiteral {
+					which_dnf,
+					clause: clause.clone(),
+					feature_id,
+				}),
+			),
 			Self::InsertOneLiteral {
 				neighbourhood_limit,
 				shuffle,
-			} => {
-				// TODO for completeness of search space
-			},
+			} => Box::new(
+				clause_options(
+					state,
+					|clause| 2 * (clause.dimension() - clause.length()),
+					|clause| {
+						(!clause.appearances())
+							.iter()
+							.flat_map(|feature_id| [(feature_id, true), (feature_id, false)])
+							.collect()
+					},
+					*neighbourhood_limit,
+					*shuffle,
+					rng,
+				)
+				.map(|(which_dnf, clause, (feature_id, parity))| Move::InsertLiteral {
+					which_dnf,
+					clause: clause.clone(),
+					feature_id,
+					parity,
+				}),
+			),
 			Self::RemoveFromAllClauses { only_same_polarities } => {
+				let mut result: Vec<Move> = Vec::new();
 				for (dnf, which_dnf) in state.dnfs() {
+					let dimension = if let Some(dimension) = dnf.dimension() {
+						dimension
+					} else {
+						continue;
+					};
 					let (mut indices_present_in_all, positive_in_all, negative_in_all_inverted) =
 						dnf.clauses().iter().fold(
-							(!Bitmap::new(), !Bitmap::new(), Bitmap::new()),
+							(
+								Bitset::full(dimension),
+								Bitset::full(dimension),
+								Bitset::new(dimension),
+							),
 							|(acc, positives_same, negatives_same), curr_clause| {
 								(
-									acc & *curr_clause.appearances(),
-									positives_same & *curr_clause.polarities(),
-									negatives_same | *curr_clause.polarities(),
+									&acc & curr_clause.appearances(),
+									&positives_same & curr_clause.polarities(),
+									&negatives_same | curr_clause.polarities(),
 								)
 							},
 						);
 
 					if *only_same_polarities {
-						indices_present_in_all &= positive_in_all | !negative_in_all_inverted;
+						indices_present_in_all &= &(&positive_in_all | &!&negative_in_all_inverted);
 					}
 
 					for index in &indices_present_in_all {
-						let cloned_dnf = DNF::new(
-							dnf.clauses()
-								.iter()
-								.copied()
-								.map(|mut clause| {
-									clause.remove_literal(index);
-									clause
-								})
-								.collect(),
-						);
-						let modified_state = Self::create_modified_state(state, cloned_dnf, which_dnf);
-						result.push(modified_state);
+						result.push(Move::RemoveFromAllClauses {
+							which_dnf,
+							feature_id: index,
+						});
 					}
 				}
-				result.shuffle(&mut thread_rng());
+				result.shuffle(rng);
 				trace!("Found {} neighbours by RemoveFromAllClauses.", result.len());
+				Box::new(result.into_iter())
 			},
 			Self::InsertOneRemoveElsewhere => {
-				let mut rng = thread_rng();
+				let mut combinations: Vec<(bool, &Clause, FeatureID, bool)> = Vec::new();
 				for (dnf, which_dnf) in state.dnfs() {
-					let not_present_in_all: Bitmap<SIZE> =
-						!dnf.clauses().iter().fold(!Bitmap::new(), |acc, curr_clause| {
-							acc & *curr_clause.appearances()
+					let dimension = if let Some(dimension) = dnf.dimension() {
+						dimension
+					} else {
+						continue;
+					};
+					let not_present_in_all: Bitset = !&dnf
+						.clauses()
+						.iter()
+						.fold(Bitset::full(dimension), |acc, curr_clause| {
+							&acc & curr_clause.appearances()
 						});
 					for index in &not_present_in_all {
-						// FIXES A BUG IN THE bitmaps CRATE!
-						if index >= SIZE {
-							continue;
-						}
-						let mut inserted = false;
-						let cloned_dnf = DNF::new(
-							dnf.clauses()
-								.iter()
-								.copied()
-								.map(|mut clause| {
-									// Literal is not present:
-									if clause.literal_at(index) == None {
-										// If no literal was inserted before, insert a
-										// random one and let no others be inserted anymore.
-										if !inserted {
-											inserted = true;
-											clause.insert_literal(index, rng.gen_bool(0.5));
-										}
-									} else {
-										// Literal was present: remove it.
-										clause.remove_literal(index);
-									}
-									clause
-								})
-								.collect(),
-						);
-
-						let modified_state = Self::create_modified_state(state, cloned_dnf, which_dnf);
-						result.push(modified_state);
+						// Insert a random literal into a random clause it is not present in.
+						let clause = dnf
+							.clauses()
+							.iter()
+							.filter(|clause| clause.literal_at(index).is_none())
+							.choose(rng)
+							.expect("The feature is not present in all clauses.");
+						combinations.push((which_dnf, clause, index, rng.gen_bool(0.5)));
 					}
 				}
-				result.shuffle(&mut rng);
-				trace!("Found {} neighbours by InsertOneRemoveElsewhere.", result.len());
+				combinations.shuffle(rng);
+				trace!(
+					"Found {} neighbours by InsertOneRemoveElsewhere.",
+					combinations.len()
+				);
+				Box::new(
+					combinations
+						.into_iter()
+						.map(
+							|(which_dnf, clause, index, parity)| Move::InsertOneRemoveElsewhere {
+								which_dnf,
+								clause: clause.clone(),
+								feature_id: index,
+								parity,
+							},
+						),
+				)
 			},
+			Self::RemoveNonBlockingLiteral {
+				neighbourhood_limit,
+				shuffle,
+			} => non_blocking_removals(
+				state,
+				positive_samples,
+				negative_samples,
+				*neighbourhood_limit,
+				*shuffle,
+				rng,
+			),
+			Self::RemovePatch {
+				width,
+				height,
+				patch_size,
+				neighbourhood_limit,
+				shuffle,
+			} => patch_removals(
+				state,
+				*width,
+				*height,
+				*patch_size,
+				*neighbourhood_limit,
+				*shuffle,
+				rng,
+			),
+			Self::RemoveRedundantClause => redundant_clause_removals(state, rng),
+			Self::MergeClauses {
+				neighbourhood_limit,
+				shuffle,
+			} => clause_merges(state, *neighbourhood_limit, *shuffle, rng),
+			Self::RuinAndRecreate {
+				ruined_clauses,
+				candidates,
+				neighbourhood_size,
+			} => ruin_and_recreate_moves(
+				state,
+				positive_samples,
+				negative_samples,
+				ruined_clauses.get(),
+				*candidates,
+				*neighbourhood_size,
+				rng,
+			),
 		}
-		result
 	}
 }
+
+/// Lazily generates the options of modifying each clause of both `DNF`s, e.g. the
+/// literals to remove from it, together with the clause.
+///
+/// With a limit, the options are a random sample of all options, which is indexed
+/// instead of collecting all options. Otherwise, the options of one clause after another
+/// are generated, in random order if shuffled. All randomness is drawn before returning.
+fn clause_options<'a, T: 'a>(
+	state: &'a State,
+	option_count: fn(&Clause) -> usize,
+	options: fn(&Clause) -> Vec<T>,
+	neighbourhood_limit: Option<usize>,
+	shuffle: bool,
+	rng: &mut StdRng,
+) -> Box<dyn Iterator<Item = (bool, &'a Clause, T)> + 'a> {
+	let mut clauses: Vec<(bool, &Clause)> = state
+		.dnfs()
+		.into_iter()
+		.flat_map(|(dnf, which_dnf)| dnf.clauses().iter().map(move |clause| (which_dnf, clause)))
+		.collect();
+
+	if let Some(limit) = neighbourhood_limit {
+		// The options of the clauses up to and including each clause.
+		let ends: Vec<usize> = clauses
+			.iter()
+			.scan(0, |end, (_, clause)| {
+				*end += option_count(clause);
+				Some(*end)
+			})
+			.collect();
+		let total = ends.last().copied().unwrap_or_default();
+		let indices = index::sample(rng, total, limit.min(total));
+		return Box::new(indices.into_iter().map(move |index| {
+			let position = ends.partition_point(|end| *end <= index);
+			let start = position.checked_sub(1).map_or(0, |previous| ends[previous]);
+			let (which_dnf, clause) = clauses[position];
+			(which_dnf, clause, options(clause).swap_remove(index - start))
+		}));
+	}
+
+	if shuffle {
+		clauses.shuffle(rng);
+	}
+	let mut options_rng = StdRng::seed_from_u64(rng.gen());
+	Box::new(clauses.into_iter().flat_map(move |(which_dnf, clause)| {
+		let mut options = options(clause);
+		if shuffle {
+			options.shuffle(&mut options_rng);
+		}
+		options.into_iter().map(move |option| (which_dnf, clause, option))
+	}))
+}
+
+/// Returns the removals of literals which are not blocking w.r.t. the samples of the
+/// opposite class.
+fn non_blocking_removals<'a>(
+	state: &'a State,
+	positive_samples: &SampleStore,
+	negative_samples: &SampleStore,
+	neighbourhood_limit: Option<usize>,
+	shuffle: bool,
+	rng: &mut StdRng,
+) -> Box<dyn Iterator<Item = Move> + 'a> {
+	let mut combinations = non_blocking_literals(state, positive_samples, negative_samples);
+
+	if shuffle {
+		combinations.shuffle(rng);
+	}
+
+	if let Some(limit) = neighbourhood_limit {
+		combinations = combinations.choose_multiple(rng, limit).copied().collect();
+	}
+
+	trace!(
+		"Found {} neighbours by RemoveNonBlockingLiteral.",
+		combinations.len()
+	);
+	Box::new(
+		combinations
+			.into_iter()
+			.map(|(which_dnf, clause, feature_id)| Move::RemoveLiteral {
+				which_dnf,
+				clause: clause.clone(),
+				feature_id,
+			}),
+	)
+}
+
+/// Returns the literals of both `DNF`s which are not blocking w.r.t. the samples of the
+/// opposite class.
+/// Literals of clauses of length one are omitted, as empty clauses do not cover any
+/// samples.
+fn non_blocking_literals<'a>(
+	state: &'a State,
+	positive_samples: &SampleStore,
+	negative_samples: &SampleStore,
+) -> Vec<(bool, &'a Clause, FeatureID)> {
+	state
+		.dnfs()
+		.into_iter()
+		.flat_map(|(dnf, which_dnf)| {
+			let opposite_samples = if which_dnf {
+				negative_samples
+			} else {
+				positive_samples
+			};
+			dnf.clauses()
+				.par_iter()
+				.filter(|clause| clause.length() > 1)
+				.flat_map_iter(|clause| {
+					let non_blocking = clause.appearances() & &!&clause.blocking_literals(opposite_samples);
+					non_blocking
+						.iter()
+						.map(|feature_id| (which_dnf, clause, feature_id))
+						.collect::<Vec<(bool, &Clause, FeatureID)>>()
+				})
+				.collect::<Vec<(bool, &Clause, FeatureID)>>()
+		})
+		.collect()
+}
+
+/// Returns the removals of the literals of patches of pixels from clauses.
+fn patch_removals<'a>(
+	state: &'a State,
+	width: usize,
+	height: usize,
+	patch_size: usize,
+	neighbourhood_limit: Option<usize>,
+	shuffle: bool,
+	rng: &mut StdRng,
+) -> Box<dyn Iterator<Item = Move> + 'a> {
+	let mut combinations = patch_literals(state, width, height, patch_size);
+
+	if shuffle {
+		combinations.shuffle(rng);
+	}
+
+	if let Some(limit) = neighbourhood_limit {
+		combinations = combinations.choose_multiple(rng, limit).cloned().collect();
+	}
+
+	trace!("Found {} neighbours by RemovePatch.", combinations.len());
+	Box::new(
+		combinations
+			.into_iter()
+			.map(|(which_dnf, clause, feature_ids)| Move::RemoveLiterals {
+				which_dnf,
+				clause: clause.clone(),
+				feature_ids,
+			}),
+	)
+}
+
+/// Returns the literals of every patch of pixels in every clause of both `DNF`s, for all
+/// patches intersecting the clause.
+fn patch_literals(
+	state: &State,
+	width: usize,
+	height: usize,
+	patch_size: usize,
+) -> Vec<(bool, &Clause, Vec<FeatureID>)> {
+	let dimension = match state
+		.dnfs()
+		.iter()
+		.find_map(|(dnf, _)| dnf.clauses().iter().next())
+	{
+		Some(clause) if patch_size > 0 && patch_size <= width.min(height) => clause.dimension(),
+		_ => return Vec::new(),
+	};
+	let patches: Vec<Bitset> = (0..=height - patch_size)
+		.flat_map(|top| (0..=width - patch_size).map(move |left| (top, left)))
+		.map(|(top, left)| {
+			let mut patch = Bitset::new(dimension);
+			for row in top..top + patch_size {
+				for column in left..left + patch_size {
+					let feature_id = row * width + column;
+					if feature_id < dimension {
+						patch.set(feature_id, true);
+					}
+				}
+			}
+			patch
+		})
+		.collect();
+	state
+		.dnfs()
+		.into_iter()
+		.flat_map(|(dnf, which_dnf)| {
+			dnf.clauses()
+				.par_iter()
+				.flat_map_iter(|clause| {
+					patches
+						.iter()
+						.map(|patch| clause.appearances() & patch)
+						.filter(|literals| !literals.is_empty())
+						.map(|literals| (which_dnf, clause, literals.iter().collect()))
+						.collect::<Vec<(bool, &Clause, Vec<FeatureID>)>>()
+				})
+				.collect::<Vec<(bool, &Clause, Vec<FeatureID>)>>()
+		})
+		.collect()
+}
+
+/// Returns the removals of all redundant clauses of both `DNF`s in random order.
+fn redundant_clause_removals<'a>(state: &'a State, rng: &mut StdRng) -> Box<dyn Iterator<Item = Move> + 'a> {
+	let mut result: Vec<Move> = state
+		.dnfs()
+		.into_iter()
+		.flat_map(|(_, which_dnf)| {
+			state
+				.redundant_clauses(which_dnf)
+				.into_iter()
+				.map(move |clause| Move::RemoveClause {
+					which_dnf,
+					clause: clause.clone(),
+				})
+		})
+		.collect();
+	result.shuffle(rng);
+	trace!("Found {} neighbours by RemoveRedundantClause.", result.len());
+	Box::new(result.into_iter())
+}
+
+/// Returns the merges of pairs of clauses of the same `DNF`.
+fn clause_merges<'a>(
+	state: &'a State,
+	neighbourhood_limit: Option<usize>,
+	shuffle: bool,
+	rng: &mut StdRng,
+) -> Box<dyn Iterator<Item = Move> + 'a> {
+	let dnfs: Vec<(Vec<&Clause>, bool)> = state
+		.dnfs()
+		.into_iter()
+		.map(|(dnf, which_dnf)| (dnf.clauses().iter().collect(), which_dnf))
+		.collect();
+	let positive_pairs = pair_count(dnfs[0].0.len());
+	let pairs = positive_pairs + pair_count(dnfs[1].0.len());
+
+	// Index the pairs instead of collecting them, as there are quadratically many.
+	let indices: Vec<usize> = if let Some(limit) = neighbourhood_limit {
+		index::sample(rng, pairs, limit.min(pairs)).into_vec()
+	} else {
+		let mut indices: Vec<usize> = (0..pairs).collect();
+		if shuffle {
+			indices.shuffle(rng);
+		}
+		indices
+	};
+
+	trace!("Found {} neighbours by MergeClauses.", indices.len());
+	Box::new(indices.into_iter().map(move |index| {
+		let (clauses, which_dnf, index) = if index < positive_pairs {
+			(&dnfs[0].0, dnfs[0].1, index)
+		} else {
+			(&dnfs[1].0, dnfs[1].1, index - positive_pairs)
+		};
+		let (first, second) = pair_at(index, clauses.len());
+		Move::MergeClauses {
+			which_dnf,
+			first: clauses[first].clone(),
+			second: clauses[second].clone(),
+		}
+	}))
+}
+
+/// Returns `neighbourhood_size` neighbours generated by `ruin_and_recreate`.
+#[allow(clippy::too_many_arguments)]
+fn ruin_and_recreate_moves<'a>(
+	state: &'a State,
+	positive_samples: &'a SampleStore,
+	negative_samples: &'a SampleStore,
+	ruined_clauses: usize,
+	candidates: usize,
+	neighbourhood_size: usize,
+	rng: &mut StdRng,
+) -> Box<dyn Iterator<Item = Move> + 'a> {
+	trace!("Found {} neighbours by RuinAndRecreate.", neighbourhood_size);
+	// Construct the neighbours lazily, as each of them is expensive to construct.
+	Box::new(
+		draw_seeds(rng, neighbourhood_size)
+			.into_iter()
+			.filter_map(move |seed| {
+				ruin_and_recreate(
+					state,
+					positive_samples,
+					negative_samples,
+					ruined_clauses,
+					candidates,
+					&mut StdRng::seed_from_u64(seed),
+				)
+			}),
+	)
+}
+
+/// Removes randomly chosen clauses of a randomly chosen non-empty `DNF` and covers the
+/// samples thereby uncovered again by greedily choosing among the maximal generalizations
+/// of some of them.
+/// Returns `None` if both `DNF`s are empty or if exactly the removed clauses are
+/// recreated.
+fn ruin_and_recreate(
+	state: &State,
+	positive_samples: &SampleStore,
+	negative_samples: &SampleStore,
+	ruined_clauses: usize,
+	candidates: usize,
+	rng: &mut StdRng,
+) -> Option<Move> {
+	let (dnf, which_dnf) = state
+		.dnfs()
+		.into_iter()
+		.filter(|(dnf, _)| !dnf.clauses().is_empty())
+		.choose(rng)?;
+	let (own_samples, opposite_samples) = if which_dnf {
+		(positive_samples, negative_samples)
+	} else {
+		(negative_samples, positive_samples)
+	};
+	let removed = dnf.clauses().iter().choose_multiple(rng, ruined_clauses);
+	let mut uncovered = state.uncovered_without(which_dnf, &removed);
+
+	let mut inserted = Vec::new();
+	while !uncovered.is_empty() {
+		let uncovered_samples: Vec<usize> = uncovered.iter().collect();
+		let seeds: Vec<(usize, Vec<FeatureID>)> = uncovered_samples
+			.choose_multiple(rng, candidates.max(1))
+			.map(|&index| {
+				let mut feature_order: Vec<FeatureID> = (0..own_samples.dimension()).collect();
+				feature_order.shuffle(rng);
+				(index, feature_order)
+			})
+			.collect();
+		let (clause, newly_covered) = seeds
+			.into_par_iter()
+			.map(|(index, feature_order)| {
+				let mut clause = Clause::from(&Sample::new(which_dnf, own_samples.features_of(index)));
+				clause.generalize(opposite_samples, &feature_order);
+				let newly_covered = &clause.covered(own_samples) & &uncovered;
+				(clause, newly_covered)
+			})
+			.max_by_key(|(_, newly_covered)| newly_covered.count_ones())
+			.expect("At least one candidate is generated for the uncovered samples.");
+		if newly_covered.is_empty() {
+			// Only happens for samples of both classes with equal features.
+			break;
+		}
+		uncovered &= &!&newly_covered;
+		inserted.push(clause);
+	}
+	if inserted.len() == removed.len() && inserted.iter().all(|clause| removed.contains(&clause)) {
+		return None;
+	}
+
+	Some(Move::ReplaceClauses {
+		which_dnf,
+		removed: removed.into_iter().cloned().collect(),
+		inserted,
+	})
+}
+
+/// Returns the number of unordered pairs of `count` distinct items.
+const fn pair_count(count: usize) -> usize { count * count.saturating_sub(1) / 2 }
+
+/// Returns the pair of distinct items `(first, second)` with `first < second` at the
+/// given index, where the pairs of `count` items are enumerated lexicographically.
+fn pair_at(index: usize, count: usize) -> (usize, usize) {
+	// The pairs with `first = i` start at `pair_count(count) - pair_count(count - i)`.
+	let offset = |first: usize| pair_count(count) - pair_count(count - first);
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_precision_loss,
+		clippy::cast_sign_loss
+	)]
+	let mut first = {
+		let remaining = (pair_count(count) - index) as f64;
+		count.saturating_sub(((1.0 + 8.0_f64.mul_add(remaining, 1.0).sqrt()) / 2.0).ceil() as usize)
+	};
+	// Correct rounding errors of the estimate.
+	while first > 0 && offset(first) > index {
+		first -= 1;
+	}
+	while offset(first + 1) <= index {
+		first += 1;
+	}
+	(first, first + 1 + index - offset(first))
+}
@@ -0,0 +1,32 @@
+//! Provides the interface of neighbourhood operators, which generate the moves to the
+//! neighbours of a state.
+
+use std::fmt::Debug;
+use rand::rngs::StdRng;
+
+use crate::algorithms::local_search::moves::Move;
+use crate::algorithms::local_search::state::State;
+use crate::boolean_formulae::sample_store::SampleStore;
+
+/// A strategy to generate the neighbourhood of a state.
+///
+/// Besides the built-in `NeighbourhoodGenerator`s, operators can be registered in an
+/// `OperatorRegistry` to reference them by name in the run config.
+/// Operators are given the sample stores along with the state, s.t. they can restrict
+/// themselves to moves which keep the `DNF`s exact, using the coverage of the clauses.
+pub trait NeighbourhoodOperator: Debug + Send + Sync {
+	/// Generates the moves to the neighbourhood of the state, whose `DNF`s must be exact
+	/// w.r.t. the positive and negative samples, respectively.
+	///
+	/// The moves should be generated lazily, s.t. consumers only looking for some of the
+	/// neighbours do not pay for constructing all of them.
+	/// All randomness must be drawn from `rng` before returning, s.t. the neighbourhood
+	/// only depends on the state of `rng`.
+	fn generate_neighbourhood<'a>(
+		&self,
+		state: &'a State,
+		positive_samples: &'a SampleStore,
+		negative_samples: &'a SampleStore,
+		rng: &mut StdRng,
+	) -> Box<dyn Iterator<Item = Move> + 'a>;
+}
@@ -0,0 +1,112 @@
+//! Provides the registry of neighbourhood operators, s.t. custom operators can be
+//! referenced by name in the run config.
+
+use std::collections::{
+	BTreeMap,
+	HashMap,
+};
+use serde::de::Error;
+use serde::{
+	Deserialize,
+	Deserializer,
+	Serialize,
+};
+use serde_json::Value;
+
+use crate::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use crate::algorithms::local_search::neighbourhood_operator::NeighbourhoodOperator;
+
+/// Creates a neighbourhood operator from its parameters in the run config.
+pub type OperatorFactory = fn(Value) -> Result<Box<dyn NeighbourhoodOperator>, serde_json::Error>;
+
+/// Error Enum for creating neighbourhood operators from the run config.
+#[derive(Debug)]
+pub enum ErrorKind {
+	/// No operator is registered under the name.
+	UnknownOperator(String),
+	/// The config does not map exactly one name to the parameters of the operator.
+	MalformedConfig,
+	/// The factory of the operator could not parse its parameters.
+	InvalidParameters(serde_json::Error),
+}
+
+/// Configures one neighbourhood operator in the run config.
+///
+/// Both variants share the same format: an object mapping the name of the operator to
+/// its parameters, e.g. `{"RemoveOneLiteral": {"neighbourhood_limit": 100, "shuffle":
+/// true}}`.
+/// If the name is one of `NeighbourhoodGenerator::NAMES`, the config is deserialized as
+/// the built-in generator and its errors are reported, otherwise it references an
+/// operator of the registry.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum OperatorConfig {
+	/// One of the built-in neighbourhood generators.
+	BuiltIn(NeighbourhoodGenerator),
+	/// An operator of the registry.
+	Registered(BTreeMap<String, Value>),
+}
+
+impl<'de> Deserialize<'de> for OperatorConfig {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let value = Value::deserialize(deserializer)?;
+		let name = match &value {
+			Value::String(name) => Some(name.as_str()),
+			Value::Object(entries) if entries.len() == 1 => entries.keys().next().map(String::as_str),
+			_ => None,
+		};
+		if name.map_or(false, |name| NeighbourhoodGenerator::NAMES.contains(&name)) {
+			NeighbourhoodGenerator::deserialize(value)
+				.map(OperatorConfig::BuiltIn)
+				.map_err(D::Error::custom)
+		} else {
+			BTreeMap::deserialize(value)
+				.map(OperatorConfig::Registered)
+				.map_err(D::Error::custom)
+		}
+	}
+}
+
+/// Maps names to the factories of custom neighbourhood operators.
+#[derive(Debug, Clone, Default)]
+pub struct OperatorRegistry {
+	/// The factories by the names the operators are referenced by.
+	factories: HashMap<String, OperatorFactory>,
+}
+
+impl OperatorRegistry {
+	/// Registers the factory of an operator under the name, replacing any factory
+	/// previously registered under it.
+	/// Built-in generators take precedence over registered operators of the same name.
+	pub fn register(&mut self, name: &str, factory: OperatorFactory) {
+		self.factories.insert(name.to_string(), factory);
+	}
+
+	/// Creates the configured operator.
+	///
+	/// # Errors
+	/// Will return `ErrorKind::UnknownOperator` if no operator is registered under the
+	/// configured name, `ErrorKind::MalformedConfig` if the config does not contain
+	/// exactly one name and `ErrorKind::InvalidParameters` if the factory rejects the
+	/// parameters.
+	pub fn create(&self, config: &OperatorConfig) -> Result<Box<dyn NeighbourhoodOperator>, ErrorKind> {
+		match config {
+			OperatorConfig::BuiltIn(generator) => Ok(Box::new(*generator)),
+			OperatorConfig::Registered(entries) => {
+				let mut entries = entries.iter();
+				let (name, parameters) = match (entries.next(), entries.next()) {
+					(Some(entry), None) => entry,
+					_ => return Err(ErrorKind::MalformedConfig),
+				};
+				let factory = self
+					.factories
+					.get(name)
+					.ok_or_else(|| ErrorKind::UnknownOperator(name.clone()))?;
+				factory(parameters.clone()).map_err(ErrorKind::InvalidParameters)
+			},
+		}
+	}
+}
@@ -0,0 +1,209 @@
+//! Provides the adaptive selection of the neighbourhood operator evaluated in each step.
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use serde::{
+	Serialize,
+	Deserialize,
+};
+
+/// Distinguishes the strategies to select which neighbourhood operators are evaluated in
+/// a step.
+///
+/// The adaptive strategies treat the operators as arms of a multi-armed bandit, which
+/// are rewarded if the step they were selected in improved the regularizer value.
+/// As they restrict each step to one operator, they can not be combined with the
+/// `VariableNeighbourhoodSearch`, which selects operators by itself.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Default)]
+pub enum OperatorSelection {
+	/// Evaluates all operators in every step.
+	#[default]
+	All,
+	/// Selects the operator with the highest upper confidence bound of its success rate
+	/// (UCB1), after selecting every operator once.
+	UpperConfidenceBound {
+		/// Weights the exploration of rarely selected operators against the exploitation
+		/// of successful ones.
+		exploration: f64,
+	},
+	/// Selects operators with probabilities proportional to their recent success rate.
+	ProbabilityMatching {
+		/// How much each reward changes the estimated success rate, between 0 and 1.
+		adaptation_rate: f64,
+		/// The minimum probability of any operator to be selected, at most the reciprocal
+		/// of the number of operators.
+		min_probability: f64,
+	},
+}
+
+impl OperatorSelection {
+	/// Returns whether the parameters of the strategy are in range for selecting among
+	/// the given number of operators.
+	#[must_use]
+	pub fn is_valid(&self, operator_count: usize) -> bool {
+		match *self {
+			Self::All => true,
+			Self::UpperConfidenceBound { exploration } => exploration.is_finite() && exploration >= 0.0,
+			Self::ProbabilityMatching {
+				adaptation_rate,
+				min_probability,
+			} => {
+				#[allow(clippy::cast_precision_loss)]
+				let max_probability = 1.0 / operator_count as f64;
+				adaptation_rate > 0.0
+					&& adaptation_rate <= 1.0
+					&& (0.0..=max_probability).contains(&min_probability)
+			},
+		}
+	}
+}
+
+/// How one operator performed when selected adaptively.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Default)]
+pub struct OperatorStatistics {
+	/// How often the operator was selected.
+	pub selections:   u32,
+	/// How often the steps the operator was selected in improved the regularizer value.
+	pub improvements: u32,
+	/// The estimated success rate of the operator the selection is based on.
+	pub quality:      f64,
+}
+
+/// Selects the operators by a strategy and keeps track of their statistics.
+#[derive(Debug, Clone)]
+pub struct OperatorSelector {
+	/// By which strategy to select the operators.
+	selection:  OperatorSelection,
+	/// The statistics of every operator.
+	statistics: Vec<OperatorStatistics>,
+	/// Which operators did not yield any acceptable neighbour since the last improvement.
+	exhausted:  Vec<bool>,
+	/// The operator selected for the current step.
+	selected:   Option<usize>,
+}
+
+impl OperatorSelector {
+	/// Creates a selector among the given number of operators.
+	///
+	/// # Panics
+	/// Panics if the parameters of the strategy are out of range, see
+	/// `OperatorSelection::is_valid`.
+	#[must_use]
+	pub fn new(selection: OperatorSelection, operator_count: usize) -> Self {
+		assert!(
+			selection.is_valid(operator_count),
+			"The parameters of {:?} are out of range for {} operators.",
+			selection,
+			operator_count
+		);
+		Self {
+			selection,
+			statistics: vec![OperatorStatistics::default(); operator_count],
+			exhausted: vec![false; operator_count],
+			selected: None,
+		}
+	}
+
+	/// Selects the operator to evaluate in the next step among those which are not
+	/// exhausted, or `None` if all operators are evaluated.
+	pub fn select(&mut self, rng: &mut StdRng) -> Option<usize> {
+		let available: Vec<usize> = (0..self.statistics.len())
+			.filter(|index| !self.exhausted[*index])
+			.collect();
+		self.selected = match self.selection {
+			OperatorSelection::All => None,
+			OperatorSelection::UpperConfidenceBound { exploration } => {
+				let total_selections: u32 = self
+					.statistics
+					.iter()
+					.map(|statistics| statistics.selections)
+					.sum();
+				let bound = |statistics: &OperatorStatistics| {
+					let uncertainty =
+						(f64::from(total_selections).ln() / f64::from(statistics.selections)).sqrt();
+					exploration.mul_add(uncertainty, statistics.quality)
+				};
+				available
+					.iter()
+					.copied()
+					.find(|index| self.statistics[*index].selections == 0)
+					.or_else(|| {
+						available.iter().copied().max_by(|first, second| {
+							bound(&self.statistics[*first]).total_cmp(&bound(&self.statistics[*second]))
+						})
+					})
+			},
+			OperatorSelection::ProbabilityMatching { min_probability, .. } => {
+				let total_quality: f64 = available
+					.iter()
+					.map(|index| self.statistics[*index].quality)
+					.sum();
+				#[allow(clippy::cast_precision_loss)]
+				let count = available.len() as f64;
+				let mut threshold: f64 = rng.gen();
+				available
+					.iter()
+					.copied()
+					.find(|index| {
+						threshold -= if total_quality > 0.0 {
+							count
+								.mul_add(-min_probability, 1.0)
+								.mul_add(self.statistics[*index].quality / total_quality, min_probability)
+						} else {
+							1.0 / count
+						};
+						threshold < 0.0
+					})
+					// Rounding errors may leave the threshold slightly positive.
+					.or_else(|| available.last().copied())
+			},
+		};
+		if let Some(index) = self.selected {
+			self.statistics[index].selections += 1;
+		}
+		self.selected
+	}
+
+	/// Rewards the selected operator, depending on whether the step it was selected in
+	/// improved the regularizer value.
+	/// After an improvement, exhausted operators are available again.
+	pub fn reward(&mut self, improved: bool) {
+		if let Some(index) = self.selected {
+			let statistics = &mut self.statistics[index];
+			let reward = if improved { 1.0 } else { 0.0 };
+			let step_size = match self.selection {
+				OperatorSelection::ProbabilityMatching { adaptation_rate, .. } => adaptation_rate,
+				_ => 1.0 / f64::from(statistics.selections),
+			};
+			statistics.quality += step_size * (reward - statistics.quality);
+			if improved {
+				statistics.improvements += 1;
+				self.exhausted.fill(false);
+			}
+		}
+	}
+
+	/// Marks the selected operator as exhausted after it did not yield any acceptable
+	/// neighbour.
+	/// Returns whether all operators are exhausted.
+	pub fn exhaust(&mut self) -> bool {
+		self.selected.map_or(true, |index| {
+			self.exhausted[index] = true;
+			self.exhausted.iter().all(|exhausted| *exhausted)
+		})
+	}
+
+	/// Returns the number of operators to select from.
+	#[must_use]
+	pub fn operator_count(&self) -> usize { self.statistics.len() }
+
+	/// Returns the statistics of the operators, or `None` if all operators are evaluated
+	/// in every step.
+	#[must_use]
+	pub fn statistics(&self) -> Option<&[OperatorStatistics]> {
+		match self.selection {
+			OperatorSelection::All => None,
+			_ => Some(&self.statistics),
+		}
+	}
+}
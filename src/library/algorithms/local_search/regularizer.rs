@@ -1,15 +1,14 @@
 //! Provides different regularizers for `DNF`s.
 
-use std::hash::Hash;
-use bitmaps::{
-	Bits,
-	BitsImpl,
-};
 use serde::{
 	Serialize,
 	Deserialize,
 };
-use crate::algorithms::local_search::state::State;
+use crate::algorithms::local_search::moves::Modification;
+use crate::algorithms::local_search::state::{
+	Measures,
+	State,
+};
 
 /// Distinguishes different strategies to regularize a DNF.
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
@@ -20,23 +19,57 @@ pub enum Regularizer {
 	Length,
 	/// Penalize the sum of depth and length of the DNF.
 	DepthAndLength,
+	/// Penalize a weighted sum of the measures of each `DNF`, which are in turn weighted
+	/// per `DNF`.
+	Weighted {
+		/// The weight of the length of a `DNF`.
+		length:   f64,
+		/// The weight of the depth of a `DNF`.
+		depth:    f64,
+		/// The weight of the number of clauses of a `DNF`.
+		clauses:  f64,
+		/// The weight of the terms of the positive `DNF`.
+		positive: f64,
+		/// The weight of the terms of the negative `DNF`.
+		negative: f64,
+	},
 }
 
 impl Regularizer {
 	/// Return the regularization value for the DNF according to the chosen strategy.
-	pub fn regularize<const SIZE: usize>(self, state: &State<SIZE>) -> u32
-	where
-		BitsImpl<SIZE>: Bits,
-		<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-	{
+	#[must_use]
+	pub fn regularize(self, state: &State) -> f64 { self.value(state.measures()) }
+
+	/// Return the regularization value the state would have after applying the
+	/// modification, without applying it.
+	#[must_use]
+	pub fn regularize_after(self, state: &State, modification: &Modification) -> f64 {
+		self.value(state.measures_after(modification))
+	}
+
+	/// Computes the regularization value from the measures of the positive and negative
+	/// `DNF`.
+	fn value(self, (positive, negative): (Measures, Measures)) -> f64 {
 		match self {
-			Self::Depth => state.positive_dnf.depth() + state.negative_dnf.depth(),
-			Self::Length => state.positive_dnf.length() + state.negative_dnf.length(),
+			Self::Depth => f64::from(positive.depth + negative.depth),
+			Self::Length => f64::from(positive.length + negative.length),
 			Self::DepthAndLength => {
-				state.positive_dnf.length()
-					+ state.positive_dnf.depth()
-					+ state.negative_dnf.length()
-					+ state.negative_dnf.depth()
+				f64::from(positive.length + positive.depth + negative.length + negative.depth)
+			},
+			Self::Weighted {
+				length,
+				depth,
+				clauses,
+				positive: positive_weight,
+				negative: negative_weight,
+			} => {
+				let terms = |measures: Measures| {
+					length.mul_add(
+						f64::from(measures.length),
+						depth.mul_add(f64::from(measures.depth), clauses * f64::from(measures.clauses)),
+					)
+				};
+				positive_weight.mul_add(terms(positive), negative_weight * terms(negative))
 			},
 		}
 	}
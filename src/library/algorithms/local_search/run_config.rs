@@ -1,54 +1,79 @@
 //! Provides configuration parameters for a run of the search algorithm.
 use crate::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use crate::algorithms::local_search::operator_registry::OperatorConfig;
+use crate::algorithms::local_search::operator_selection::OperatorSelection;
 use crate::algorithms::local_search::regularizer::Regularizer;
 use serde::{
 	Serialize,
 	Deserialize,
 };
 use crate::algorithms::local_search::algorithms::Algorithm;
+use crate::algorithms::local_search::termination_criteria::TerminationCriteria;
 
 /// Holds information about all possibly configurable parameters of a run.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct RunConfig<const DATA_DIM: usize> {
+pub struct RunConfig {
 	/// Can be used to show what was intended with this run config.
 	comment:                      String,
 	/// How often this configuration should be run.
 	pub run_count:                u32,
 	/// The path to the data sample file.
 	pub data_path:                String,
+	/// The dimension of the data's feature space.
+	/// If not provided, it is read from the data sample file.
+	pub data_dimension:           Option<usize>,
+	/// Width and height of the images visualizing samples and `DNF`s.
+	/// If not provided, square images are assumed.
+	pub image_dimensions:         Option<(u32, u32)>,
 	/// The metrics for this run.
 	pub metrics:                  Option<Metrics>,
-	/// Strategies for neighbourhood generation.
-	pub neighbourhood_generators: Vec<NeighbourhoodGenerator>,
+	/// Strategies for neighbourhood generation, either built-in or registered operators.
+	pub neighbourhood_generators: Vec<OperatorConfig>,
+	/// How to select which of the neighbourhood operators are evaluated in each step.
+	#[serde(default)]
+	pub operator_selection:       OperatorSelection,
 	/// Regularizer strategy.
 	pub regularizer:              Regularizer,
 	/// Which Algorithm to use.
 	pub algorithm:                Algorithm,
+	/// When to terminate the algorithm besides its own criteria.
+	#[serde(default)]
+	pub termination:              TerminationCriteria,
+	/// The seed of all randomness of the runs, the run with number `n` is seeded with
+	/// `seed + n - 1`.
+	/// If not provided, a random seed is used.
+	#[serde(default)]
+	pub seed:                     Option<u64>,
 }
 
-impl<const DATA_DIM: usize> Default for RunConfig<DATA_DIM> {
+impl Default for RunConfig {
 	fn default() -> Self {
 		Self {
 			comment:                  "The default config.".to_string(),
 			run_count:                1,
 			data_path:                "data/prepared_data.json".to_string(),
+			data_dimension:           None,
+			image_dimensions:         None,
 			metrics:                  Some(Metrics {
 				picture_frequency:     50,
 				regularizer_frequency: 50,
 			}),
 			neighbourhood_generators: vec![
-				NeighbourhoodGenerator::RemoveOneLiteral {
+				OperatorConfig::BuiltIn(NeighbourhoodGenerator::RemoveOneLiteral {
 					neighbourhood_limit: Some(100),
 					shuffle:             true,
-				},
-				NeighbourhoodGenerator::RemoveFromAllClauses {
+				}),
+				OperatorConfig::BuiltIn(NeighbourhoodGenerator::RemoveFromAllClauses {
 					only_same_polarities: true,
-				},
+				}),
 			],
+			operator_selection:       OperatorSelection::All,
 			regularizer:              Regularizer::DepthAndLength,
 			algorithm:                Algorithm::BasicHillClimber {
 				max_iterations: 10000,
 			},
+			termination:              TerminationCriteria::default(),
+			seed:                     None,
 		}
 	}
 }
@@ -0,0 +1,29 @@
+//! Provides the summary of a run which is written to its output directory.
+
+use serde::{
+	Serialize,
+	Deserialize,
+};
+
+use crate::algorithms::local_search::operator_selection::OperatorStatistics;
+use crate::algorithms::local_search::termination_criteria::TerminationReason;
+
+/// Summarizes how a run went.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunSummary {
+	/// The seed the run was started with, rerunning with it reproduces the run.
+	pub seed:                    u64,
+	/// Why the run terminated.
+	pub termination_reason:      Option<TerminationReason>,
+	/// How many iterations the algorithm performed.
+	pub iterations:              u32,
+	/// How many neighbours were evaluated for feasibility.
+	pub feasibility_evaluations: u64,
+	/// The regularizer value of the best found state.
+	pub best_value:              f64,
+	/// The wall-clock time of the run in seconds.
+	pub elapsed_seconds:         f64,
+	/// How each neighbourhood operator performed, in the order of the config, if they
+	/// were selected adaptively.
+	pub operator_statistics:     Option<Vec<OperatorStatistics>>,
+}
@@ -1,76 +1,374 @@
 //! Any feasible solution to the local search problem is represented using this state.
-use std::hash::Hash;
-use bitmaps::{
-	Bits,
-	BitsImpl,
-};
-use serde::{
-	Serialize,
-	Deserialize,
+use std::collections::{
+	BTreeMap,
+	HashMap,
+	HashSet,
 };
+use serde::{Serialize,};
 
-use rayon::prelude::*;
-use crate::boolean_formulae::data::Sample;
+use crate::algorithms::local_search::moves::Modification;
+use crate::boolean_formulae::bitset::Bitset;
+use crate::boolean_formulae::clause::Clause;
 use crate::boolean_formulae::dnf::DNF;
-use crate::boolean_formulae::evaluation::Evaluate;
+use crate::boolean_formulae::evaluation::Cover;
+use crate::boolean_formulae::sample_store::SampleStore;
+
+/// The samples covered by a single clause.
+#[derive(Debug, Clone)]
+struct ClauseCoverage {
+	/// The covered positive samples.
+	positive: Bitset,
+	/// The covered negative samples.
+	negative: Bitset,
+}
+
+impl ClauseCoverage {
+	/// Evaluates the coverage of a clause.
+	fn new(clause: &Clause, positive_samples: &SampleStore, negative_samples: &SampleStore) -> Self {
+		Self {
+			positive: clause.covered(positive_samples),
+			negative: clause.covered(negative_samples),
+		}
+	}
+
+	/// The covered samples of the class the `DNF` of the clause has to cover,
+	/// and of the opposite class, respectively.
+	const fn own_and_opposite(&self, which_dnf: bool) -> (&Bitset, &Bitset) {
+		if which_dnf {
+			(&self.positive, &self.negative)
+		} else {
+			(&self.negative, &self.positive)
+		}
+	}
+}
+
+/// Information about one `DNF` of the state, derived from its clauses.
+#[derive(Debug, Clone, Default)]
+struct DnfSummary {
+	/// The samples of its own class covered by at least one clause.
+	covered:          Bitset,
+	/// The samples of its own class covered by at least two clauses.
+	multiply_covered: Bitset,
+	/// For every clause length, how many clauses are of that length.
+	lengths:          BTreeMap<usize, usize>,
+}
+
+/// Size measures of a `DNF`, which regularizers are computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Measures {
+	/// The sum of all clause lengths.
+	pub length:  u32,
+	/// The maximum of all clause lengths.
+	pub depth:   u32,
+	/// The number of clauses.
+	pub clauses: u32,
+}
 
 /// The current state of the algorithm, i.e. the two DNFs.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct State<const SIZE: usize>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+///
+/// Besides the `DNF`s, the state caches which samples are covered by each of their
+/// clauses, s.t. moves to neighbouring states can be evaluated by only looking at the
+/// modified clauses.
+/// Only the `DNF`s are serialized. As the cache depends on the samples, a serialized
+/// state is restored by deserializing its `DNF`s and passing them to `State::new`.
+#[derive(Debug, Clone, Serialize)]
+pub struct State {
 	/// Classifying DNF of the positive samples.
-	pub(crate) positive_dnf: DNF<SIZE>,
+	pub(crate) positive_dnf: DNF,
 	/// Classifying DNF of the negative samples.
-	pub(crate) negative_dnf: DNF<SIZE>,
+	pub(crate) negative_dnf: DNF,
+	/// The samples covered by each clause of the `DNF`s.
+	#[serde(skip)]
+	coverage:                HashMap<Clause, ClauseCoverage>,
+	/// Summary of the positive `DNF`.
+	#[serde(skip)]
+	positive_summary:        DnfSummary,
+	/// Summary of the negative `DNF`.
+	#[serde(skip)]
+	negative_summary:        DnfSummary,
 }
 
-impl<const SIZE: usize> State<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl State {
+	/// Creates a new state from the positive and negative `DNF` and evaluates the
+	/// coverage of their clauses on the samples.
+	#[must_use]
+	pub fn new(
+		positive_dnf: DNF,
+		negative_dnf: DNF,
+		positive_samples: &SampleStore,
+		negative_samples: &SampleStore,
+	) -> Self {
+		let mut state = Self {
+			positive_dnf,
+			negative_dnf,
+			coverage: HashMap::new(),
+			positive_summary: DnfSummary::default(),
+			negative_summary: DnfSummary::default(),
+		};
+		for clause in state
+			.positive_dnf
+			.clauses()
+			.iter()
+			.chain(state.negative_dnf.clauses())
+		{
+			state.coverage.insert(
+				clause.clone(),
+				ClauseCoverage::new(clause, positive_samples, negative_samples),
+			);
+		}
+		state.summarize(true, positive_samples.len());
+		state.summarize(false, negative_samples.len());
+		state
+	}
+
 	/// Whether the state is feasible under the data,
 	/// i.e. the positive and negative DNF exactly classify the positive and negative
 	/// samples, respectively.
-	pub fn is_feasible(&self, positive_samples: &[Sample<SIZE>], negative_samples: &[Sample<SIZE>]) -> bool {
-		let positive_feasible = positive_samples
-			.par_iter()
-			.map(|x| self.positive_dnf.evaluate(x) && !self.negative_dnf.evaluate(x))
-			.all(|x| x);
-		let negative_feasible = negative_samples
-			.par_iter()
-			.map(|x| self.negative_dnf.evaluate(x) && !self.positive_dnf.evaluate(x))
-			.all(|x| x);
-
-		positive_feasible && negative_feasible
+	#[must_use]
+	pub fn is_feasible(&self, positive_samples: &SampleStore, negative_samples: &SampleStore) -> bool {
+		// Each clause must not cover any sample of the opposite class …
+		let exclusive = self.dnfs().iter().all(|(dnf, which_dnf)| {
+			dnf.clauses()
+				.iter()
+				.all(|clause| self.coverage[clause].own_and_opposite(*which_dnf).1.is_empty())
+		});
+		// … and all clauses together must cover all samples of their own class.
+		exclusive
+			&& self.positive_summary.covered.count_ones() == positive_samples.len()
+			&& self.negative_summary.covered.count_ones() == negative_samples.len()
+	}
+
+	/// Whether the state is still feasible after applying the modification to it,
+	/// given that it is feasible now.
+	///
+	/// Only the modified clauses are evaluated on the samples.
+	#[must_use]
+	pub fn is_feasible_after(
+		&self,
+		modification: &Modification,
+		positive_samples: &SampleStore,
+		negative_samples: &SampleStore,
+	) -> bool {
+		let which_dnf = modification.which_dnf();
+		let (own_samples, opposite_samples) = if which_dnf {
+			(positive_samples, negative_samples)
+		} else {
+			(negative_samples, positive_samples)
+		};
+
+		// Inserted clauses must not cover any sample of the opposite class …
+		let mut inserted_covered = Bitset::new(own_samples.len());
+		for clause in modification.inserted() {
+			if let Some(coverage) = self.coverage.get(clause) {
+				let (own, opposite) = coverage.own_and_opposite(which_dnf);
+				if !opposite.is_empty() {
+					return false;
+				}
+				inserted_covered |= own;
+			} else {
+				if !clause.covered(opposite_samples).is_empty() {
+					return false;
+				}
+				inserted_covered |= &clause.covered(own_samples);
+			}
+		}
+
+		// … and together with the remaining clauses they must still cover all samples of
+		// their own class.
+		let summary = self.summary(which_dnf);
+		let remaining_covered = match modification.removed() {
+			[] => summary.covered.clone(),
+			[removed] => {
+				// Only samples covered by no other clause become uncovered.
+				let (own, _) = self.coverage[removed].own_and_opposite(which_dnf);
+				let solely_covered = own & &!&summary.multiply_covered;
+				&summary.covered & &!&solely_covered
+			},
+			removed => {
+				let removed: HashSet<&Clause> = removed.iter().collect();
+				self.dnf(which_dnf)
+					.clauses()
+					.iter()
+					.filter(|clause| !removed.contains(clause))
+					.fold(Bitset::new(own_samples.len()), |mut covered, clause| {
+						covered |= self.coverage[clause].own_and_opposite(which_dnf).0;
+						covered
+					})
+			},
+		};
+		(&remaining_covered | &inserted_covered).count_ones() == own_samples.len()
 	}
 
-	/// Removes empty clauses from the `DNF`s of the state.
-	pub fn remove_empty_clauses(&mut self) {
-		for clause in self.positive_dnf.clauses().clone() {
-			if clause.is_empty() {
-				self.positive_dnf.remove_clause(&clause);
+	/// Applies the modification to the state.
+	pub fn apply(
+		&mut self,
+		modification: Modification,
+		positive_samples: &SampleStore,
+		negative_samples: &SampleStore,
+	) {
+		let which_dnf = modification.which_dnf();
+		let (removed, inserted) = modification.into_parts();
+		let dnf = if which_dnf {
+			&mut self.positive_dnf
+		} else {
+			&mut self.negative_dnf
+		};
+		for clause in &removed {
+			dnf.remove_clause(clause);
+		}
+		for clause in inserted {
+			if !self.coverage.contains_key(&clause) {
+				let coverage = ClauseCoverage::new(&clause, positive_samples, negative_samples);
+				self.coverage.insert(clause.clone(), coverage);
 			}
+			dnf.insert_clause(clause);
 		}
-		for clause in self.negative_dnf.clauses().clone() {
-			if clause.is_empty() {
-				self.negative_dnf.remove_clause(&clause);
+		for clause in &removed {
+			if !self.positive_dnf.clauses().contains(clause) && !self.negative_dnf.clauses().contains(clause)
+			{
+				self.coverage.remove(clause);
 			}
 		}
+		let sample_count = if which_dnf {
+			positive_samples.len()
+		} else {
+			negative_samples.len()
+		};
+		self.summarize(which_dnf, sample_count);
+	}
+
+	/// Returns the size measures of the positive and negative `DNF`.
+	#[must_use]
+	pub fn measures(&self) -> (Measures, Measures) {
+		(
+			Self::measures_of(&self.positive_summary.lengths),
+			Self::measures_of(&self.negative_summary.lengths),
+		)
+	}
+
+	/// Returns the size measures the positive and negative `DNF` would have after
+	/// applying the modification, without applying it.
+	#[must_use]
+	pub fn measures_after(&self, modification: &Modification) -> (Measures, Measures) {
+		let which_dnf = modification.which_dnf();
+		let mut lengths = self.summary(which_dnf).lengths.clone();
+		for clause in modification.removed() {
+			if let Some(count) = lengths.get_mut(&clause.length()) {
+				*count -= 1;
+				if *count == 0 {
+					lengths.remove(&clause.length());
+				}
+			}
+		}
+		for clause in modification.inserted() {
+			*lengths.entry(clause.length()).or_insert(0) += 1;
+		}
+		let (positive, negative) = self.measures();
+		if which_dnf {
+			(Self::measures_of(&lengths), negative)
+		} else {
+			(positive, Self::measures_of(&lengths))
+		}
 	}
 
 	/// A reference to this state's `DNF`s together with a boolean indicating whether it
 	/// is the positive `DNF`.
-	pub fn dnfs(&self) -> Vec<(&DNF<SIZE>, bool)> {
-		vec![(&self.positive_dnf, true), (&self.negative_dnf, false)]
+	#[must_use]
+	pub fn dnfs(&self) -> Vec<(&DNF, bool)> { vec![(&self.positive_dnf, true), (&self.negative_dnf, false)] }
+
+	/// A reference to the positive `DNF` if `which_dnf` is `true`,
+	/// and to the negative one otherwise.
+	#[must_use]
+	pub const fn dnf(&self, which_dnf: bool) -> &DNF {
+		if which_dnf {
+			&self.positive_dnf
+		} else {
+			&self.negative_dnf
+		}
+	}
+
+	/// Returns the clauses of one `DNF` whose covered samples are all covered by other
+	/// clauses, i.e. each of which can be removed without losing feasibility.
+	#[must_use]
+	pub fn redundant_clauses(&self, which_dnf: bool) -> Vec<&Clause> {
+		let summary = self.summary(which_dnf);
+		self.dnf(which_dnf)
+			.clauses()
+			.iter()
+			.filter(|clause| {
+				let (own, _) = self.coverage[*clause].own_and_opposite(which_dnf);
+				(own & &!&summary.multiply_covered).is_empty()
+			})
+			.collect()
+	}
+
+	/// Returns the samples of the class of one `DNF` which are only covered by the given
+	/// clauses of it, i.e. which become uncovered when removing them.
+	#[must_use]
+	pub fn uncovered_without(&self, which_dnf: bool, removed: &[&Clause]) -> Bitset {
+		let summary = self.summary(which_dnf);
+		let remaining_covered = self
+			.dnf(which_dnf)
+			.clauses()
+			.iter()
+			.filter(|clause| !removed.contains(clause))
+			.fold(Bitset::new(summary.covered.dimension()), |mut covered, clause| {
+				covered |= self.coverage[clause].own_and_opposite(which_dnf).0;
+				covered
+			});
+		&summary.covered & &!&remaining_covered
 	}
 
 	/// Whether the state's positive `DNF` is equal to the provided one.
-	pub fn positive_eq(&self, other: &DNF<SIZE>) -> bool { self.positive_dnf == *other }
+	#[must_use]
+	pub fn positive_eq(&self, other: &DNF) -> bool { self.positive_dnf == *other }
 
 	/// Whether the state's negative `DNF` is equal to the provided one.
-	pub fn negative_eq(&self, other: &DNF<SIZE>) -> bool { self.negative_dnf == *other }
+	#[must_use]
+	pub fn negative_eq(&self, other: &DNF) -> bool { self.negative_dnf == *other }
+
+	/// The summary of the positive `DNF` if `which_dnf` is `true`,
+	/// and of the negative one otherwise.
+	const fn summary(&self, which_dnf: bool) -> &DnfSummary {
+		if which_dnf {
+			&self.positive_summary
+		} else {
+			&self.negative_summary
+		}
+	}
+
+	/// Recomputes the summary of one `DNF` from the cached coverage of its clauses.
+	fn summarize(&mut self, which_dnf: bool, sample_count: usize) {
+		let mut summary = DnfSummary {
+			covered:          Bitset::new(sample_count),
+			multiply_covered: Bitset::new(sample_count),
+			lengths:          BTreeMap::new(),
+		};
+		for clause in self.dnf(which_dnf).clauses() {
+			let (own, _) = self.coverage[clause].own_and_opposite(which_dnf);
+			summary.multiply_covered |= &(&summary.covered & own);
+			summary.covered |= own;
+			*summary.lengths.entry(clause.length()).or_insert(0) += 1;
+		}
+		if which_dnf {
+			self.positive_summary = summary;
+		} else {
+			self.negative_summary = summary;
+		}
+	}
+
+	/// Computes the size measures of a `DNF` from the lengths of its clauses.
+	fn measures_of(lengths: &BTreeMap<usize, usize>) -> Measures {
+		let length = lengths
+			.iter()
+			.map(|(length, count)| length * count)
+			.sum::<usize>();
+		let depth = lengths.keys().next_back().copied().unwrap_or_default();
+		let clauses = lengths.values().sum::<usize>();
+		Measures {
+			length:  u32::try_from(length).unwrap_or(u32::MAX),
+			depth:   u32::try_from(depth).unwrap_or(u32::MAX),
+			clauses: u32::try_from(clauses).unwrap_or(u32::MAX),
+		}
+	}
 }
@@ -0,0 +1,92 @@
+//! Provides criteria to terminate a run and the reasons a run terminated for.
+
+use std::time::Duration;
+use serde::{
+	Serialize,
+	Deserialize,
+};
+
+/// Criteria to terminate a run before the algorithm terminates by itself.
+/// All provided criteria are combined, i.e. the run terminates as soon as any of them is
+/// met.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Default)]
+pub struct TerminationCriteria {
+	/// Terminate after this many iterations.
+	pub max_iterations:              Option<u32>,
+	/// Terminate after this many seconds of wall-clock time.
+	pub time_limit:                  Option<f64>,
+	/// Terminate after this many neighbours were evaluated for feasibility.
+	pub max_feasibility_evaluations: Option<u64>,
+	/// Terminate after this many iterations without improving the best found regularizer
+	/// value.
+	pub stagnation:                  Option<u32>,
+	/// Terminate as soon as a state with at most this regularizer value is found.
+	pub target_value:                Option<f64>,
+}
+
+impl TerminationCriteria {
+	/// Additionally terminates after the given number of iterations, e.g. the maximum
+	/// number of iterations of the configured algorithm.
+	#[must_use]
+	pub fn with_max_iterations(self, max_iterations: u32) -> Self {
+		Self {
+			max_iterations: Some(
+				self.max_iterations
+					.map_or(max_iterations, |limit| limit.min(max_iterations)),
+			),
+			..self
+		}
+	}
+
+	/// Returns the reason to terminate, if any of the criteria is met.
+	#[must_use]
+	pub fn check(
+		&self,
+		iterations: u32,
+		elapsed: Duration,
+		feasibility_evaluations: u64,
+		stagnating_iterations: u32,
+		best_value: f64,
+	) -> Option<TerminationReason> {
+		if self.max_iterations.map_or(false, |limit| iterations >= limit) {
+			Some(TerminationReason::MaxIterations)
+		} else if self.target_value.map_or(false, |target| best_value <= target) {
+			Some(TerminationReason::TargetValue)
+		} else if self
+			.time_limit
+			.map_or(false, |limit| elapsed.as_secs_f64() >= limit)
+		{
+			Some(TerminationReason::TimeLimit)
+		} else if self
+			.max_feasibility_evaluations
+			.map_or(false, |limit| feasibility_evaluations >= limit)
+		{
+			Some(TerminationReason::MaxFeasibilityEvaluations)
+		} else if self
+			.stagnation
+			.map_or(false, |limit| stagnating_iterations >= limit)
+		{
+			Some(TerminationReason::Stagnation)
+		} else {
+			None
+		}
+	}
+}
+
+/// Distinguishes the reasons for a run to terminate.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+pub enum TerminationReason {
+	/// The algorithm performed its maximum number of iterations.
+	MaxIterations,
+	/// The algorithm could not continue from the current state, e.g. because no feasible
+	/// neighbour improves it.
+	NoAcceptableNeighbour,
+	/// The time limit elapsed.
+	TimeLimit,
+	/// The maximum number of feasibility evaluations was reached.
+	MaxFeasibilityEvaluations,
+	/// The best found regularizer value did not improve for too many iterations.
+	Stagnation,
+	/// A state with the target regularizer value was found.
+	TargetValue,
+}
@@ -1,10 +1,5 @@
 //! This module provides the conversion to an image from clauses and DNFs respectively.
 use std::collections::HashMap;
-use std::hash::Hash;
-use bitmaps::{
-	Bits,
-	BitsImpl,
-};
 use image::{
 	Rgb,
 	RgbImage,
@@ -42,11 +37,7 @@ pub trait ToImage {
 	fn to_image(&self, width: u32, height: u32) -> Result<RgbImage, ErrorKind>;
 }
 
-impl<const SIZE: usize> ToImage for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl ToImage for Clause {
 	fn to_image(&self, width: u32, height: u32) -> Result<RgbImage, ErrorKind> {
 		let mut image = RgbImage::new(width, height);
 
@@ -69,11 +60,7 @@ where
 	}
 }
 
-impl<const SIZE: usize> ToImage for DNF<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl ToImage for DNF {
 	fn to_image(&self, width: u32, height: u32) -> Result<RgbImage, ErrorKind> {
 		#[allow(clippy::cast_possible_truncation)]
 		let clause_count = self.clauses().len() as u32;
@@ -115,16 +102,12 @@ where
 	}
 }
 
-impl<const SIZE: usize> ToImage for Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl ToImage for Sample {
 	fn to_image(&self, width: u32, height: u32) -> Result<RgbImage, ErrorKind> {
 		let mut image = RgbImage::new(width, height);
 		let features = self.features();
 
-		for id in 0..SIZE {
+		for id in 0..self.dimension() {
 			#[allow(clippy::cast_possible_truncation)]
 			let id = id as u32;
 			if id >= width * height {
@@ -0,0 +1,205 @@
+//! Provides a word-packed bitset whose dimension is only known at runtime.
+
+use std::ops::{
+	BitAnd,
+	BitAndAssign,
+	BitOr,
+	BitOrAssign,
+	BitXor,
+	BitXorAssign,
+	Not,
+};
+
+/// The type of the words bits are packed into.
+pub type Word = u64;
+
+/// The number of bits per word.
+const WORD_BITS: usize = Word::BITS as usize;
+
+/// A set of bits over a feature space of runtime dimension, packed into words.
+///
+/// Bits at indices greater or equal to the dimension are always unset,
+/// so that sets of equal dimension and content compare and hash equally.
+/// Sets are ordered by their dimension first and their words second.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Bitset {
+	/// The number of bits this set ranges over.
+	dimension: usize,
+	/// The actual bits, the bit with index `i` is stored in word `i / WORD_BITS` at
+	/// position `i % WORD_BITS`.
+	words:     Vec<Word>,
+}
+
+impl Bitset {
+	/// Creates a new `Bitset` of the given dimension with all bits unset.
+	#[must_use]
+	pub fn new(dimension: usize) -> Self {
+		Self {
+			dimension,
+			words: vec![0; (dimension + WORD_BITS - 1) / WORD_BITS],
+		}
+	}
+
+	/// Creates a new `Bitset` of the given dimension with all bits set.
+	#[must_use]
+	pub fn full(dimension: usize) -> Self {
+		let mut result = Self {
+			dimension,
+			words: vec![Word::MAX; (dimension + WORD_BITS - 1) / WORD_BITS],
+		};
+		result.clear_unused_bits();
+		result
+	}
+
+	/// Returns the number of bits this set ranges over.
+	#[must_use]
+	pub const fn dimension(&self) -> usize { self.dimension }
+
+	/// Returns the words the bits are packed into.
+	/// Bits beyond the dimension of the set are always unset.
+	#[must_use]
+	pub fn words(&self) -> &[Word] { &self.words }
+
+	/// Returns the number of set bits.
+	#[must_use]
+	pub fn count_ones(&self) -> usize { self.words.iter().map(|word| word.count_ones() as usize).sum() }
+
+	/// Whether no bit is set.
+	#[must_use]
+	pub fn is_empty(&self) -> bool { self.words.iter().all(|word| *word == 0) }
+
+	/// Returns whether the bit at `index` is set.
+	///
+	/// # Panics
+	/// Panics if `index >= self.dimension()`.
+	#[must_use]
+	pub fn get(&self, index: usize) -> bool {
+		assert!(
+			index < self.dimension,
+			"Index {} was out of bounds for bitset of dimension {}!",
+			index,
+			self.dimension
+		);
+		self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+	}
+
+	/// Sets the bit at `index` to `value` and returns its previous value.
+	///
+	/// # Panics
+	/// Panics if `index >= self.dimension()`.
+	pub fn set(&mut self, index: usize, value: bool) -> bool {
+		let previous = self.get(index);
+		let mask = 1 << (index % WORD_BITS);
+		if value {
+			self.words[index / WORD_BITS] |= mask;
+		} else {
+			self.words[index / WORD_BITS] &= !mask;
+		}
+		previous
+	}
+
+	/// Returns an iterator over the indices of all set bits in ascending order.
+	pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+		self.words.iter().enumerate().flat_map(|(word_index, word)| {
+			let mut remaining = *word;
+			std::iter::from_fn(move || {
+				if remaining == 0 {
+					None
+				} else {
+					let bit = remaining.trailing_zeros() as usize;
+					// Unset the lowest set bit.
+					remaining &= remaining - 1;
+					Some(word_index * WORD_BITS + bit)
+				}
+			})
+		})
+	}
+
+	/// Unsets all bits of the last word beyond the dimension of the set.
+	fn clear_unused_bits(&mut self) {
+		let used_bits = self.dimension % WORD_BITS;
+		if used_bits != 0 {
+			if let Some(last) = self.words.last_mut() {
+				*last &= (1 << used_bits) - 1;
+			}
+		}
+	}
+}
+
+impl<'a> IntoIterator for &'a Bitset {
+	type IntoIter = Box<dyn Iterator<Item = usize> + 'a>;
+	type Item = usize;
+
+	fn into_iter(self) -> Self::IntoIter { Box::new(self.iter()) }
+}
+
+impl BitAnd for &Bitset {
+	type Output = Bitset;
+
+	fn bitand(self, rhs: Self) -> Bitset {
+		let mut result = self.clone();
+		result &= rhs;
+		result
+	}
+}
+
+impl BitOr for &Bitset {
+	type Output = Bitset;
+
+	fn bitor(self, rhs: Self) -> Bitset {
+		let mut result = self.clone();
+		result |= rhs;
+		result
+	}
+}
+
+impl BitXor for &Bitset {
+	type Output = Bitset;
+
+	fn bitxor(self, rhs: Self) -> Bitset {
+		let mut result = self.clone();
+		result ^= rhs;
+		result
+	}
+}
+
+impl Not for &Bitset {
+	type Output = Bitset;
+
+	fn not(self) -> Bitset {
+		let mut result = Bitset {
+			dimension: self.dimension,
+			words:     self.words.iter().map(|word| !word).collect(),
+		};
+		// Inverting the whole words would also set the bits beyond the dimension.
+		result.clear_unused_bits();
+		result
+	}
+}
+
+impl BitAndAssign<&Self> for Bitset {
+	fn bitand_assign(&mut self, rhs: &Self) {
+		debug_assert_eq!(self.dimension, rhs.dimension);
+		for (word, other) in self.words.iter_mut().zip(&rhs.words) {
+			*word &= other;
+		}
+	}
+}
+
+impl BitOrAssign<&Self> for Bitset {
+	fn bitor_assign(&mut self, rhs: &Self) {
+		debug_assert_eq!(self.dimension, rhs.dimension);
+		for (word, other) in self.words.iter_mut().zip(&rhs.words) {
+			*word |= other;
+		}
+	}
+}
+
+impl BitXorAssign<&Self> for Bitset {
+	fn bitxor_assign(&mut self, rhs: &Self) {
+		debug_assert_eq!(self.dimension, rhs.dimension);
+		for (word, other) in self.words.iter_mut().zip(&rhs.words) {
+			*word ^= other;
+		}
+	}
+}
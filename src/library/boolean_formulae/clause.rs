@@ -1,12 +1,6 @@
 //! Provides representation and tools for clauses of disjunctive normal forms,
 //! i.e. conjunctions of boolean literals.
 
-use std::hash::Hash;
-use bitmaps::{
-	Bitmap,
-	Bits,
-	BitsImpl,
-};
 use log::{trace,};
 use serde::{
 	Serialize,
@@ -15,126 +9,116 @@ use serde::{
 	Deserializer,
 };
 
+use crate::boolean_formulae::ErrorKind;
+use crate::boolean_formulae::bitset::Bitset;
 use crate::boolean_formulae::data::{
 	FeatureID,
 	Sample,
 };
-use crate::boolean_formulae::evaluation::{Evaluate,};
-
-/// A Helper for easier Serialization and Deserialization Access.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-struct Literal {
-	/// The Feature this literal evaluates.
-	id:     FeatureID,
-	/// The Literal parity, i.e. `false` if the literal is negated
-	/// and `true` if it is not negated.
-	parity: bool,
-}
+use crate::boolean_formulae::evaluation::{
+	Cover,
+	Evaluate,
+};
+use crate::boolean_formulae::literal::Literal;
+use crate::boolean_formulae::sample_store::SampleStore;
 
 /// A Wrapper for easier Serialization and Deserialization Access.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct ClauseWrapper {
+	/// The dimension of the feature space the clause is defined over.
+	dimension: usize,
 	/// The Clauses Literals in order.
-	literals: Vec<Literal>,
+	literals:  Vec<Literal>,
 }
 
-impl<const SIZE: usize> From<ClauseWrapper> for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl From<ClauseWrapper> for Clause {
 	fn from(wrapper: ClauseWrapper) -> Self {
-		assert_eq!(
-			wrapper.literals.len(),
-			SIZE,
-			"Could not cast ClausWrapper to Clause due to difference in length."
-		);
-		let mut appearances: Bitmap<SIZE> = Bitmap::new();
-		let mut polarities: Bitmap<SIZE> = Bitmap::new();
-
+		let mut clause = Self::empty(wrapper.dimension);
 		for literal in wrapper.literals {
-			appearances.set(literal.id, true);
-			polarities.set(literal.id, literal.parity);
-		}
-		Self {
-			appearances,
-			polarities,
+			clause.insert_literal(literal.id(), literal.parity());
 		}
+		clause
 	}
 }
 
-impl<const SIZE: usize> From<Clause<SIZE>> for ClauseWrapper
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn from(clause: Clause<SIZE>) -> Self {
-		let mut literals = Vec::new();
-		for index in &clause.appearances {
-			literals.push(Literal {
-				id:     index,
-				parity: clause.polarities.get(index),
-			});
+impl From<&Clause> for ClauseWrapper {
+	fn from(clause: &Clause) -> Self {
+		Self {
+			dimension: clause.dimension(),
+			literals:  clause
+				.appearances
+				.iter()
+				.map(|index| Literal::new(index, clause.polarities.get(index)))
+				.collect(),
 		}
-		Self { literals }
 	}
 }
 
 /// The representation of a DNF clause, i.e. a conjunction of literals.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Clause<const SIZE: usize>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Clause {
 	/// A Bitmap representing, whether a literal is present in the clause.
-	appearances: Bitmap<SIZE>,
+	appearances: Bitset,
 	/// For the indices that are `true` in appearances, this specifies the literals
-	/// parity. For all other indices this bitmaps content is meaningless.
-	polarities:  Bitmap<SIZE>,
+	/// parity. All other indices are unset, s.t. clauses of the same literals are equal.
+	polarities:  Bitset,
 }
 
-impl<const SIZE: usize> Default for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn default() -> Self {
+impl Clause {
+	/// Constructs a new `Clause` from a vector of optional literals.
+	/// The dimension of the clause is the length of the vector.
+	///
+	/// # Panics
+	/// Panics if a literal refers to a feature outside of the clause's dimension.
+	#[must_use]
+	pub fn new(literals: Vec<Option<Literal>>) -> Self {
+		let mut clause = Self::empty(literals.len());
+		for literal in literals.into_iter().flatten() {
+			clause.insert_literal(literal.id(), literal.parity());
+		}
+		clause
+	}
+
+	/// Constructs a `Clause` without any literals over a feature space of the given
+	/// dimension.
+	#[must_use]
+	pub fn empty(dimension: usize) -> Self {
 		Self {
-			appearances: Bitmap::new(),
-			polarities:  Bitmap::new(),
+			appearances: Bitset::new(dimension),
+			polarities:  Bitset::new(dimension),
 		}
 	}
-}
 
-impl<const SIZE: usize> Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
 	/// Whether the clause is empty.
 	#[must_use]
 	pub fn is_empty(&self) -> bool { self.appearances.is_empty() }
 
 	/// Returns the length of the `Clause`, i.e. the number of literals it contains.
 	#[must_use]
-	pub fn length(&self) -> usize { self.appearances.len() }
+	pub fn length(&self) -> usize { self.appearances.count_ones() }
+
+	/// Returns the dimension of the feature space the `Clause` is defined over.
+	#[must_use]
+	pub const fn dimension(&self) -> usize { self.appearances.dimension() }
 
 	/// Returns all indices for which a literal is present in the clause.
 	#[must_use]
-	pub fn literal_indices(&self) -> Vec<FeatureID> { self.appearances.into_iter().collect() }
+	pub fn literal_indices(&self) -> Vec<FeatureID> { self.appearances.iter().collect() }
 
 	/// Returns a reference to the appearance bitmap of the clause.
-	pub fn appearances(&self) -> &Bitmap<SIZE> { &self.appearances }
+	#[must_use]
+	pub const fn appearances(&self) -> &Bitset { &self.appearances }
 
 	/// Returns a reference to the polarities bitmap of the clause.
-	pub fn polarities(&self) -> &Bitmap<SIZE> { &self.polarities }
+	#[must_use]
+	pub const fn polarities(&self) -> &Bitset { &self.polarities }
 
 	/// Returns the parity of the literal with the specified `FeatureID`.
 	/// If the literal is not present or the id is too big for this clause,
 	/// `None` is returned.
+	#[must_use]
 	pub fn literal_at(&self, feature_id: FeatureID) -> Option<bool> {
-		if feature_id >= SIZE || !self.appearances.get(feature_id) {
+		if feature_id >= self.dimension() || !self.appearances.get(feature_id) {
 			None
 		} else {
 			Some(self.polarities.get(feature_id))
@@ -146,13 +130,13 @@ where
 	/// Returns the replaced value.
 	///
 	/// # Panics
-	/// Panics if `feature_id >= SIZE`.
+	/// Panics if `feature_id >= self.dimension()`.
 	pub fn insert_literal(&mut self, feature_id: FeatureID, parity: bool) -> Option<bool> {
 		assert!(
-			feature_id < SIZE,
+			feature_id < self.dimension(),
 			"Index {} was out of bounce for clause of size {}!",
 			feature_id,
-			SIZE
+			self.dimension()
 		);
 		trace!("Trying to insert {{x_{}: {}}} into clause.", feature_id, parity);
 		if self.appearances.get(feature_id) {
@@ -170,92 +154,160 @@ where
 	/// and returns whether it was present.
 	///
 	/// # Panics
-	/// Panics if `feature_id >= SIZE`.
+	/// Panics if `feature_id >= self.dimension()`.
 	pub fn remove_literal(&mut self, feature_id: FeatureID) -> bool {
 		assert!(
-			feature_id < SIZE,
+			feature_id < self.dimension(),
 			"Index {} was out of bounce for clause of size {}!",
 			feature_id,
-			SIZE
+			self.dimension()
 		);
+		self.polarities.set(feature_id, false);
 		self.appearances.set(feature_id, false)
 	}
+
+	/// Returns the least general generalization of both clauses, i.e. the clause of the
+	/// literals both contain with equal polarity.
+	/// It is satisfied by every sample satisfying either of the clauses.
+	#[must_use]
+	pub fn generalization(&self, other: &Self) -> Self {
+		let shared = &self.appearances & &other.appearances;
+		let disagreeing = &self.polarities ^ &other.polarities;
+		let appearances = &shared & &!&disagreeing;
+		let polarities = &self.polarities & &appearances;
+		Self {
+			appearances,
+			polarities,
+		}
+	}
+
+	/// Returns the features of the blocking literals w.r.t. the samples, i.e. of those
+	/// literals which are the only ones of the clause some sample does not satisfy.
+	/// Removing any other literal does not make the clause cover further samples.
+	#[must_use]
+	pub fn blocking_literals(&self, samples: &SampleStore) -> Bitset {
+		let mut violated_once = Bitset::new(samples.len());
+		let mut violated_twice = Bitset::new(samples.len());
+		for feature_id in &self.appearances {
+			let violated = samples.samples_with(feature_id, !self.polarities.get(feature_id));
+			violated_twice |= &(&violated_once & violated);
+			violated_once |= violated;
+		}
+		let violated_only_once = &violated_once & &!&violated_twice;
+
+		let mut blocking = self.appearances.clone();
+		for feature_id in &self.appearances {
+			let violated = samples.samples_with(feature_id, !self.polarities.get(feature_id));
+			blocking.set(feature_id, !(violated & &violated_only_once).is_empty());
+		}
+		blocking
+	}
+
+	/// Removes the literals of the given features in the given order, unless the literal
+	/// is blocking w.r.t. the samples at that point, s.t. the clause does not cover any
+	/// further samples.
+	/// At least one literal is kept, as the empty clause does not cover any samples.
+	pub fn generalize(&mut self, samples: &SampleStore, feature_order: &[FeatureID]) {
+		// For every sample, the number of literals of the clause it does not satisfy.
+		let mut violation_counts = vec![0_usize; samples.len()];
+		for feature_id in &self.appearances {
+			for index in samples.samples_with(feature_id, !self.polarities.get(feature_id)) {
+				violation_counts[index] += 1;
+			}
+		}
+		let mut violated_once = Bitset::new(samples.len());
+		for (index, count) in violation_counts.iter().enumerate() {
+			violated_once.set(index, *count == 1);
+		}
+
+		for &feature_id in feature_order {
+			if self.length() <= 1 {
+				break;
+			}
+			let parity = match self.literal_at(feature_id) {
+				Some(parity) => parity,
+				None => continue,
+			};
+			let violated = samples.samples_with(feature_id, !parity);
+			if (violated & &violated_once).is_empty() {
+				for index in violated {
+					violation_counts[index] -= 1;
+					if violation_counts[index] == 1 {
+						violated_once.set(index, true);
+					}
+				}
+				self.remove_literal(feature_id);
+			}
+		}
+	}
 }
 
-impl<const SIZE: usize> Evaluate<SIZE> for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn evaluate(&self, data: &Sample<SIZE>) -> bool {
+impl Evaluate for Clause {
+	fn evaluate(&self, data: &Sample) -> Result<bool, ErrorKind> {
+		if data.dimension() < self.dimension() {
+			return Err(ErrorKind::InsufficientData(data.dimension()));
+		}
 		if self.is_empty() {
-			return false;
+			return Ok(false);
 		}
 
-		// XOR is a toggled inverter
+		// XOR is a toggled inverter, so a literal is violated iff its feature assignment
+		// differs from its polarity. Checking a whole word of literals at once:
 		// polarity of atom			| 0 0 1 1
-		// !polarity of atom		| 1 1 0 0
 		// feature assignment		| 0 1 0 1
 		// ===================================
-		// !p.o.a XOR f.a.			| 1 0 0 1
-		let all_evaluated = data.features() ^ !self.polarities;
+		// p.o.a XOR f.a.			| 0 1 1 0
+		Ok(self
+			.appearances
+			.words()
+			.iter()
+			.zip(self.polarities.words())
+			.zip(data.features().words())
+			.all(|((appearances, polarities), features)| (features ^ polarities) & appearances == 0))
+	}
+}
 
+impl Cover for Clause {
+	fn covered(&self, samples: &SampleStore) -> Bitset {
+		if self.is_empty() {
+			return Bitset::new(samples.len());
+		}
+
+		// A sample satisfies the conjunction iff it satisfies every single literal.
 		self.appearances
-			.into_iter()
-			.map(|index| all_evaluated.get(index))
-			.all(|x| x)
+			.iter()
+			.fold(samples.all(), |mut covered, feature_id| {
+				covered &= samples.samples_with(feature_id, self.polarities.get(feature_id));
+				covered
+			})
 	}
 }
 
-impl<const SIZE: usize> From<&Sample<SIZE>> for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn from(sample: &Sample<SIZE>) -> Self {
+impl From<&Sample> for Clause {
+	fn from(sample: &Sample) -> Self {
 		Self {
-			appearances: Bitmap::mask(SIZE),
-			polarities:  sample.features(),
+			appearances: Bitset::full(sample.dimension()),
+			polarities:  sample.features().clone(),
 		}
 	}
 }
-impl<const SIZE: usize> Serialize for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+
+impl Serialize for Clause {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
 	{
-		let wrapper = ClauseWrapper::from(self.clone());
+		let wrapper = ClauseWrapper::from(self);
 		wrapper.serialize(serializer)
 	}
 }
 
-impl<'de, const SIZE: usize> Deserialize<'de> for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl<'de> Deserialize<'de> for Clause {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
 		let wrapper = ClauseWrapper::deserialize(deserializer)?;
-		Ok(Clause::from(wrapper))
+		Ok(Self::from(wrapper))
 	}
 }
-
-unsafe impl<const SIZE: usize> Send for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-}
-unsafe impl<const SIZE: usize> Sync for Clause<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-}
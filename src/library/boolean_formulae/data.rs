@@ -1,17 +1,14 @@
 //! Provides representation of boolean data over multidimensional feature-space.
 
-use std::hash::Hash;
-use bitmaps::{
-	Bitmap,
-	Bits,
-	BitsImpl,
-};
 use serde::{
 	Serialize,
 	Deserialize,
 	Serializer,
 	Deserializer,
 };
+
+use crate::boolean_formulae::bitset::Bitset;
+
 /// Identifier type for features of data.
 pub type FeatureID = usize;
 
@@ -24,41 +21,15 @@ struct SampleWrapper {
 	features: Vec<bool>,
 }
 
-impl<const SIZE: usize> From<SampleWrapper> for Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn from(wrapper: SampleWrapper) -> Self {
-		assert_eq!(
-			wrapper.features.len(),
-			SIZE,
-			"Could not cast ClauseWrapper to Clause due to difference in length: \
-			 ClauseWrapper.features.len(): {}, SIZE: {}",
-			wrapper.features.len(),
-			SIZE
-		);
-		let mut features: Bitmap<SIZE> = Bitmap::new();
-		for (index, &feature) in wrapper.features.iter().enumerate() {
-			features.set(index, feature);
-		}
-		Self {
-			label: wrapper.label,
-			features,
-		}
-	}
+impl From<SampleWrapper> for Sample {
+	fn from(wrapper: SampleWrapper) -> Self { Self::new(wrapper.label, wrapper.features) }
 }
 
-impl<const SIZE: usize> From<Sample<SIZE>> for SampleWrapper
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn from(sample: Sample<SIZE>) -> Self {
+impl From<Sample> for SampleWrapper {
+	fn from(sample: Sample) -> Self {
 		Self {
 			label:    sample.label,
-			features: (0..sample.features.len())
-				.into_iter()
+			features: (0..sample.dimension())
 				.map(|index| sample.features.get(index))
 				.collect(),
 		}
@@ -66,26 +37,28 @@ where
 }
 
 /// Represents a manifestation of the feature space in a bitmap format.
-#[derive(Debug, Clone, Copy)]
-pub struct Sample<const SIZE: usize>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+#[derive(Debug, Clone)]
+pub struct Sample {
 	/// The label of the sample.
 	label:    bool,
 	/// The data of the sample.
-	features: Bitmap<SIZE>,
+	features: Bitset,
 }
 
-impl<const SIZE: usize> Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl Sample {
 	/// Creates a sample from a manifestation of a feature space.
+	/// The dimension of the sample is the number of provided features.
 	#[must_use]
-	pub const fn new(label: bool, features: Bitmap<SIZE>) -> Self { Self { label, features } }
+	pub fn new(label: bool, features: Vec<bool>) -> Self {
+		let mut bitset = Bitset::new(features.len());
+		for (index, feature) in features.into_iter().enumerate() {
+			bitset.set(index, feature);
+		}
+		Self {
+			label,
+			features: bitset,
+		}
+	}
 
 	/// Returns the label of the sample.
 	#[must_use]
@@ -93,28 +66,24 @@ where
 
 	/// Returns the features of the sample.
 	#[must_use]
-	pub const fn features(&self) -> Bitmap<{ SIZE }> { self.features }
+	pub const fn features(&self) -> &Bitset { &self.features }
+
+	/// Returns the dimension of the sample's feature space.
+	#[must_use]
+	pub const fn dimension(&self) -> usize { self.features.dimension() }
 }
 
-impl<const SIZE: usize> Serialize for Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl Serialize for Sample {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
 	{
-		let wrapper = SampleWrapper::from(*self);
+		let wrapper = SampleWrapper::from(self.clone());
 		wrapper.serialize(serializer)
 	}
 }
 
-impl<'de, const SIZE: usize> Deserialize<'de> for Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl<'de> Deserialize<'de> for Sample {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>,
@@ -123,15 +92,3 @@ where
 		Ok(Self::from(wrapper))
 	}
 }
-unsafe impl<const SIZE: usize> Send for Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-}
-unsafe impl<const SIZE: usize> Sync for Sample<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-}
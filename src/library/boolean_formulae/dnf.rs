@@ -1,40 +1,37 @@
 //! Provides representation for disjunctive normal form boolean formulae.
 
-use std::collections::{HashSet,};
-use std::hash::Hash;
-use bitmaps::{
-	Bits,
-	BitsImpl,
-};
+use std::collections::{BTreeSet,};
 use rayon::prelude::*;
 use serde::{
 	Serialize,
 	Deserialize,
 };
 
+use crate::boolean_formulae::ErrorKind;
+use crate::boolean_formulae::bitset::Bitset;
 use crate::boolean_formulae::clause::Clause;
 use crate::boolean_formulae::data::{Sample,};
-use crate::boolean_formulae::evaluation::{Evaluate,};
+use crate::boolean_formulae::evaluation::{
+	Cover,
+	Evaluate,
+};
+use crate::boolean_formulae::sample_store::SampleStore;
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 /// The representation of a DNF, i.e. a disjunction of clauses.
-pub struct DNF<const SIZE: usize>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	/// The disjunction of clauses.
-	clauses: HashSet<Clause<SIZE>>,
+pub struct DNF {
+	/// The disjunction of clauses, ordered s.t. iterating them is deterministic.
+	clauses: BTreeSet<Clause>,
 }
 
-impl<const SIZE: usize> DNF<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+impl DNF {
 	/// Constructs a new `DNF` from a vector of clauses.
 	#[must_use]
-	pub fn new(clauses: HashSet<Clause<SIZE>>) -> Self { Self { clauses } }
+	pub fn new(clauses: Vec<Clause>) -> Self {
+		Self {
+			clauses: clauses.into_iter().collect(),
+		}
+	}
 
 	/// Returns the length of the `DNF`, i.e. the sum of all its clauses' lengths.
 	#[must_use]
@@ -55,30 +52,73 @@ where
 			.unwrap_or_default()
 	}
 
+	/// Returns the dimension of the feature space the clauses of the `DNF` are defined
+	/// over, or `None` if the `DNF` contains no clauses.
+	#[must_use]
+	pub fn dimension(&self) -> Option<usize> { self.clauses.iter().next().map(Clause::dimension) }
+
 	/// Returns a reference to the clauses of the `DNF`.
 	#[must_use]
-	pub const fn clauses(&self) -> &HashSet<Clause<SIZE>> { &self.clauses }
+	pub const fn clauses(&self) -> &BTreeSet<Clause> { &self.clauses }
 
 	/// Returns a mutable reference to the clauses of the `DNF`.
 	#[must_use]
-	pub fn mut_clauses(&mut self) -> &mut HashSet<Clause<SIZE>> { &mut self.clauses }
+	pub fn mut_clauses(&mut self) -> &mut BTreeSet<Clause> { &mut self.clauses }
 
 	/// Removes the clause from the `DNF` and returns whether it was present.
-	pub fn remove_clause(&mut self, clause: &Clause<SIZE>) -> bool { self.clauses.remove(clause) }
+	pub fn remove_clause(&mut self, clause: &Clause) -> bool { self.clauses.remove(clause) }
 
 	/// Inserts a clause into the `DNF` and returns whether it was newly inserted.
-	pub fn insert_clause(&mut self, clause: Clause<SIZE>) -> bool { self.clauses.insert(clause) }
+	pub fn insert_clause(&mut self, clause: Clause) -> bool { self.clauses.insert(clause) }
+
+	/// Removes clauses whose covered samples are all covered by other clauses, s.t. the
+	/// `DNF` still covers the same samples, and returns the removed clauses.
+	/// Longer clauses are removed first, as removing them shortens the `DNF` the most.
+	pub fn remove_redundant_clauses(&mut self, samples: &SampleStore) -> Vec<Clause> {
+		let mut coverage: Vec<(&Clause, Bitset)> = self
+			.clauses
+			.par_iter()
+			.map(|clause| (clause, clause.covered(samples)))
+			.collect();
+		coverage.sort_by_key(|(clause, _)| std::cmp::Reverse(clause.length()));
+
+		let mut cover_counts = vec![0_usize; samples.len()];
+		for (_, covered) in &coverage {
+			for index in covered {
+				cover_counts[index] += 1;
+			}
+		}
+		let mut removed = Vec::new();
+		for (clause, covered) in coverage {
+			if covered.iter().all(|index| cover_counts[index] > 1) {
+				for index in &covered {
+					cover_counts[index] -= 1;
+				}
+				removed.push(clause.clone());
+			}
+		}
+		for clause in &removed {
+			self.clauses.remove(clause);
+		}
+		removed
+	}
+}
+
+impl Evaluate for DNF {
+	fn evaluate(&self, data: &Sample) -> Result<bool, ErrorKind> {
+		self.clauses
+			.par_iter()
+			.map(|clause| clause.evaluate(data))
+			.try_reduce(|| false, |a, b| Ok(a || b))
+	}
 }
 
-impl<const SIZE: usize> Evaluate<SIZE> for DNF<SIZE>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
-	fn evaluate(&self, data: &Sample<SIZE>) -> bool {
+impl Cover for DNF {
+	fn covered(&self, samples: &SampleStore) -> Bitset {
+		// A sample satisfies the disjunction iff it satisfies any of the clauses.
 		self.clauses
 			.par_iter()
-			.map(|literal| literal.evaluate(data))
-			.any(|x| x)
+			.map(|clause| clause.covered(samples))
+			.reduce(|| Bitset::new(samples.len()), |a, b| &a | &b)
 	}
 }
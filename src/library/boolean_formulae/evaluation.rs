@@ -1,19 +1,27 @@
 //! Provides the `Evaluate` trait every boolean formula should implement.
 
-use std::hash::Hash;
-use bitmaps::{
-	Bits,
-	BitsImpl,
-};
+use crate::boolean_formulae::ErrorKind;
+use crate::boolean_formulae::bitset::Bitset;
 use crate::boolean_formulae::data::{Sample,};
+use crate::boolean_formulae::sample_store::SampleStore;
 
 /// The general semantic of everything that can be evaluated under a variable assignment
 /// (e.g. Clauses and DNFs).
-pub trait Evaluate<const SIZE: usize>
-where
-	BitsImpl<SIZE>: Bits,
-	<BitsImpl<{ SIZE }> as Bits>::Store: Hash,
-{
+pub trait Evaluate {
 	/// Evaluates itself under the given variable assignment.
-	fn evaluate(&self, data: &Sample<SIZE>) -> bool;
+	///
+	/// # Errors
+	/// Will return `ErrorKind::InsufficientData` if the assignment has fewer features
+	/// than the formula refers to.
+	fn evaluate(&self, data: &Sample) -> Result<bool, ErrorKind>;
+}
+
+/// The semantic of evaluating a boolean formula under all samples of a `SampleStore` at
+/// once.
+pub trait Cover {
+	/// Returns the set of samples in the store which the formula evaluates to `true` on.
+	///
+	/// # Panics
+	/// Panics if the samples have fewer features than the formula refers to.
+	fn covered(&self, samples: &SampleStore) -> Bitset;
 }
@@ -0,0 +1,32 @@
+//! Provides representation of single boolean literals.
+
+use serde::{
+	Serialize,
+	Deserialize,
+};
+
+use crate::boolean_formulae::data::FeatureID;
+
+/// A boolean literal, i.e. a possibly negated feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Literal {
+	/// The Feature this literal evaluates.
+	id:     FeatureID,
+	/// The Literal parity, i.e. `false` if the literal is negated
+	/// and `true` if it is not negated.
+	parity: bool,
+}
+
+impl Literal {
+	/// Creates a new literal over the feature with the given id.
+	#[must_use]
+	pub const fn new(id: FeatureID, parity: bool) -> Self { Self { id, parity } }
+
+	/// Returns the `FeatureID` this literal evaluates.
+	#[must_use]
+	pub const fn id(&self) -> FeatureID { self.id }
+
+	/// Returns the parity of the literal.
+	#[must_use]
+	pub const fn parity(&self) -> bool { self.parity }
+}
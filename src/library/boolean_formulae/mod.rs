@@ -1,4 +1,15 @@
 pub mod evaluation;
+pub mod bitset;
 pub mod data;
+pub mod sample_store;
+pub mod literal;
 pub mod clause;
 pub mod dnf;
+
+/// Error Enum for working with boolean formulae over data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+	/// The data has fewer features than the formula refers to.
+	/// Contains the dimension of the data.
+	InsufficientData(usize),
+}
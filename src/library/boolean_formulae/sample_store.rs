@@ -0,0 +1,95 @@
+//! Provides a column-major (bitsliced) storage of samples for fast batch evaluation.
+
+use crate::boolean_formulae::bitset::Bitset;
+use crate::boolean_formulae::data::{
+	FeatureID,
+	Sample,
+};
+
+/// Stores samples feature by feature, i.e. for every feature the set of samples
+/// assigning `true` (and `false` respectively) to it.
+///
+/// This way the samples satisfying a literal can be looked up directly
+/// and formulae can be evaluated on all samples at once by combining these sets.
+#[derive(Debug, Clone)]
+pub struct SampleStore {
+	/// The number of stored samples.
+	sample_count:  usize,
+	/// For every feature, the set of samples in which it is `true`.
+	true_samples:  Vec<Bitset>,
+	/// For every feature, the set of samples in which it is `false`.
+	false_samples: Vec<Bitset>,
+}
+
+impl SampleStore {
+	/// Creates a store of the given dimension from samples, which must all be of this
+	/// dimension.
+	/// Sample `i` of the slice is identified by index `i` in the sets of the store.
+	///
+	/// # Panics
+	/// Panics if a sample is not of the given dimension.
+	#[must_use]
+	pub fn new(dimension: usize, samples: &[Sample]) -> Self {
+		let mut true_samples = vec![Bitset::new(samples.len()); dimension];
+		for (sample_index, sample) in samples.iter().enumerate() {
+			assert_eq!(
+				sample.dimension(),
+				dimension,
+				"Could not store a sample of dimension {} in a store of dimension {}.",
+				sample.dimension(),
+				dimension
+			);
+			for feature_id in sample.features() {
+				true_samples[feature_id].set(sample_index, true);
+			}
+		}
+		let false_samples = true_samples.iter().map(|samples| !samples).collect();
+		Self {
+			sample_count: samples.len(),
+			true_samples,
+			false_samples,
+		}
+	}
+
+	/// Returns the number of stored samples.
+	#[must_use]
+	pub const fn len(&self) -> usize { self.sample_count }
+
+	/// Whether the store contains no samples.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool { self.sample_count == 0 }
+
+	/// Returns the dimension of the stored samples' feature space.
+	#[must_use]
+	pub fn dimension(&self) -> usize { self.true_samples.len() }
+
+	/// Returns the set of all stored samples.
+	#[must_use]
+	pub fn all(&self) -> Bitset { Bitset::full(self.sample_count) }
+
+	/// Returns the features of the sample with the given index.
+	///
+	/// # Panics
+	/// Panics if `index >= self.len()`.
+	#[must_use]
+	pub fn features_of(&self, index: usize) -> Vec<bool> {
+		self.true_samples
+			.iter()
+			.map(|samples| samples.get(index))
+			.collect()
+	}
+
+	/// Returns the set of samples assigning `value` to the feature,
+	/// i.e. the samples satisfying the literal `{x_feature_id: value}`.
+	///
+	/// # Panics
+	/// Panics if `feature_id >= self.dimension()`.
+	#[must_use]
+	pub fn samples_with(&self, feature_id: FeatureID, value: bool) -> &Bitset {
+		if value {
+			&self.true_samples[feature_id]
+		} else {
+			&self.false_samples[feature_id]
+		}
+	}
+}
@@ -36,6 +36,7 @@ use std::time::{Instant,};
 
 use log::{
 	debug,
+	error,
 	info,
 };
 use proof::algorithms::local_search::local_search;
@@ -50,7 +51,7 @@ fn main() {
 		.filter_level(arguments.log_level())
 		.init();
 
-	let config: RunConfig<784> = arguments.config.as_deref().map_or_else(
+	let config: RunConfig = arguments.config.as_deref().map_or_else(
 		|| {
 			debug!("No custom config file provided. Starting PROOF with default config file.");
 			RunConfig::default()
@@ -84,6 +85,9 @@ fn main() {
 	let start_time = Instant::now();
 
 	info!("Starting execution of local search algorithm.");
-	local_search(&config);
+	if let Err(error) = local_search(&config) {
+		error!("Could not set up the local search: {:?}", error);
+		std::process::exit(1);
+	}
 	info!("Program execution took {:?}", start_time.elapsed());
 }
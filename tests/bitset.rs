@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+use proof::boolean_formulae::bitset::Bitset;
+
+#[test]
+fn beyond_1024_bits() {
+	let mut bitset = Bitset::new(3136);
+	assert!(bitset.is_empty());
+	assert!(!bitset.set(3135, true));
+	assert!(bitset.set(3135, true));
+	bitset.set(1024, true);
+	bitset.set(0, true);
+	assert_eq!(3, bitset.count_ones());
+	assert_eq!(vec![0, 1024, 3135], bitset.iter().collect::<Vec<usize>>());
+}
+
+#[test]
+fn negation_stays_within_dimension() {
+	let bitset = Bitset::new(70);
+	let negated = !&bitset;
+	assert_eq!(70, negated.count_ones());
+	assert_eq!(Bitset::full(70), negated);
+	assert_eq!(bitset, !&negated);
+}
+
+#[test]
+fn binary_operations() {
+	let mut first = Bitset::new(130);
+	let mut second = Bitset::new(130);
+	first.set(1, true);
+	first.set(129, true);
+	second.set(129, true);
+	second.set(64, true);
+
+	assert_eq!(vec![129], (&first & &second).iter().collect::<Vec<usize>>());
+	assert_eq!(
+		vec![1, 64, 129],
+		(&first | &second).iter().collect::<Vec<usize>>()
+	);
+	assert_eq!(vec![1, 64], (&first ^ &second).iter().collect::<Vec<usize>>());
+}
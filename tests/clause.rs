@@ -4,8 +4,12 @@ use proof::boolean_formulae::data::{
 	Sample,
 };
 use proof::boolean_formulae::ErrorKind;
-use proof::boolean_formulae::evaluation::{Evaluate,};
+use proof::boolean_formulae::evaluation::{
+	Cover,
+	Evaluate,
+};
 use proof::boolean_formulae::literal::Literal;
+use proof::boolean_formulae::sample_store::SampleStore;
 
 #[test]
 fn clause_length() {
@@ -60,3 +64,83 @@ fn insufficient_data() {
 		clause.evaluate(&insufficient_sample)
 	);
 }
+
+#[test]
+fn clause_evaluation_beyond_1024_features() {
+	let mut features = vec![false; 3136];
+	features[3000] = true;
+	let sample = Sample::new(true, features);
+	let clause = Clause::from(&sample);
+
+	assert_eq!(3136, clause.length());
+	assert_eq!(Ok(true), clause.evaluate(&sample));
+
+	let mut other_features = vec![false; 3136];
+	other_features[2000] = true;
+	other_features[3000] = true;
+	assert_eq!(Ok(false), clause.evaluate(&Sample::new(false, other_features)));
+}
+
+#[test]
+fn generalization() {
+	let first = Clause::from(&Sample::new(true, vec![true, false, true, true]));
+	let second = Clause::from(&Sample::new(true, vec![true, true, true, false]));
+
+	let expected = Clause::new(vec![
+		Some(Literal::new(0, true)),
+		None,
+		Some(Literal::new(2, true)),
+		None,
+	]);
+	assert_eq!(expected, first.generalization(&second));
+	assert_eq!(expected, second.generalization(&first));
+}
+
+#[test]
+fn removal_restores_equality() {
+	let mut clause = Clause::from(&Sample::new(true, vec![true, true]));
+	clause.remove_literal(1);
+
+	assert_eq!(Clause::new(vec![Some(Literal::new(0, true)), None]), clause);
+}
+
+#[test]
+fn blocking_literals() {
+	let clause = Clause::from(&Sample::new(true, vec![true, false, true]));
+	let samples = SampleStore::new(
+		3,
+		&[
+			// Only excluded by the literal of feature 0.
+			Sample::new(false, vec![false, false, true]),
+			// Excluded by all literals.
+			Sample::new(false, vec![false, true, false]),
+		],
+	);
+
+	assert_eq!(
+		vec![0],
+		clause
+			.blocking_literals(&samples)
+			.iter()
+			.collect::<Vec<FeatureID>>()
+	);
+}
+
+#[test]
+fn generalize_maximally() {
+	let mut clause = Clause::from(&Sample::new(true, vec![true, false, true, false]));
+	let samples = SampleStore::new(
+		4,
+		&[
+			Sample::new(false, vec![false, false, true, false]),
+			Sample::new(false, vec![true, true, false, true]),
+		],
+	);
+	clause.generalize(&samples, &[0, 1, 2, 3]);
+
+	// The literal of feature 0 excludes the first sample, so only one of the others is
+	// needed to exclude the second sample.
+	assert_eq!(vec![0, 3], clause.literal_indices());
+	assert!(clause.covered(&samples).is_empty());
+	assert_eq!(clause.appearances(), &clause.blocking_literals(&samples));
+}
@@ -0,0 +1,75 @@
+//! Fixtures shared by the integration tests.
+// Not every test crate uses every fixture.
+#![allow(dead_code)]
+
+use proof::algorithms::local_search::moves::Move;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::NeighbourhoodOperator;
+use proof::boolean_formulae::clause::Clause;
+use proof::boolean_formulae::data::Sample;
+use proof::boolean_formulae::dnf::DNF;
+use proof::boolean_formulae::sample_store::SampleStore;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Positive and negative samples of 2x2 pixels.
+pub fn samples() -> (Vec<Sample>, Vec<Sample>) {
+	(
+		vec![
+			Sample::new(true, vec![true, false, false, true]),
+			Sample::new(true, vec![true, true, false, false]),
+			Sample::new(true, vec![true, true, false, true]),
+			Sample::new(true, vec![true, false, true, true]),
+		],
+		vec![
+			Sample::new(false, vec![false, false, false, true]),
+			Sample::new(false, vec![false, true, true, false]),
+			Sample::new(false, vec![false, true, true, true]),
+		],
+	)
+}
+
+pub fn stores() -> (SampleStore, SampleStore) {
+	let (positive_samples, negative_samples) = samples();
+	(
+		SampleStore::new(4, &positive_samples),
+		SampleStore::new(4, &negative_samples),
+	)
+}
+
+/// A clause over the features of the samples with the given literals.
+pub fn clause(literals: &[(usize, bool)]) -> Clause {
+	let mut clause = Clause::empty(4);
+	for (feature_id, parity) in literals {
+		clause.insert_literal(*feature_id, *parity);
+	}
+	clause
+}
+
+/// The initial state, with one clause per sample.
+pub fn state(positive_store: &SampleStore, negative_store: &SampleStore) -> State {
+	let (positive_samples, negative_samples) = samples();
+	State::new(
+		DNF::new(positive_samples.iter().map(Clause::from).collect()),
+		DNF::new(negative_samples.iter().map(Clause::from).collect()),
+		positive_store,
+		negative_store,
+	)
+}
+
+/// Generates the neighbourhood of the state with a fixed seed.
+pub fn neighbours(
+	operator: &dyn NeighbourhoodOperator,
+	state: &State,
+	positive_store: &SampleStore,
+	negative_store: &SampleStore,
+) -> Vec<Move> {
+	operator
+		.generate_neighbourhood(
+			state,
+			positive_store,
+			negative_store,
+			&mut StdRng::seed_from_u64(0),
+		)
+		.collect()
+}
@@ -0,0 +1,58 @@
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::{
+	local_search,
+	ErrorKind,
+};
+use proof::boolean_formulae::data::Sample;
+
+mod common;
+
+/// Writes the samples to a datafile of the given name and runs the local search on it.
+fn search(name: &str, samples: &[Sample], data_dimension: Option<usize>) -> Result<(), ErrorKind> {
+	let data_path = std::env::temp_dir().join(name);
+	std::fs::write(&data_path, serde_json::to_string(samples).unwrap()).unwrap();
+	let mut config = RunConfig::default();
+	config.data_path = data_path.to_string_lossy().into_owned();
+	config.data_dimension = data_dimension;
+	local_search(&config)
+}
+
+#[test]
+fn rejects_empty_datafile() {
+	assert!(matches!(
+		search("proof-rejects-empty-datafile.json", &[], None),
+		Err(ErrorKind::NoSamples)
+	));
+}
+
+#[test]
+fn rejects_wrong_sample_dimension() {
+	let (mut positive_samples, negative_samples) = common::samples();
+	positive_samples.push(Sample::new(true, vec![true, false]));
+	let samples = [positive_samples, negative_samples].concat();
+
+	assert!(matches!(
+		search("proof-rejects-wrong-sample-dimension.json", &samples, None),
+		Err(ErrorKind::WrongSampleDimension(2, 4))
+	));
+	assert!(matches!(
+		search("proof-rejects-wrong-sample-dimension.json", &samples, Some(2)),
+		Err(ErrorKind::WrongSampleDimension(4, 2))
+	));
+}
+
+#[test]
+fn rejects_unreadable_datafile() {
+	let mut config = RunConfig::default();
+	config.data_path = "does/not/exist.json".to_string();
+	assert!(matches!(local_search(&config), Err(ErrorKind::UnreadableData(_))));
+}
+
+#[test]
+fn rejects_invalid_datafile() {
+	let data_path = std::env::temp_dir().join("proof-rejects-invalid-datafile.json");
+	std::fs::write(&data_path, "[{\"label\": true}").unwrap();
+	let mut config = RunConfig::default();
+	config.data_path = data_path.to_string_lossy().into_owned();
+	assert!(matches!(local_search(&config), Err(ErrorKind::InvalidData(_))));
+}
@@ -6,6 +6,7 @@ use proof::boolean_formulae::data::{
 use proof::boolean_formulae::dnf::DNF;
 use proof::boolean_formulae::evaluation::Evaluate;
 use proof::boolean_formulae::literal::Literal;
+use proof::boolean_formulae::sample_store::SampleStore;
 
 #[test]
 pub fn evaluate() {
@@ -26,3 +27,30 @@ pub fn evaluate() {
 
 	assert_eq!(Ok(true), dnf.evaluate(&sample));
 }
+
+#[test]
+pub fn remove_redundant_clauses() {
+	let samples = vec![
+		Sample::new(true, vec![true, false, true]),
+		Sample::new(true, vec![true, true, false]),
+		Sample::new(true, vec![false, true, true]),
+	];
+	let first_true = Clause::new(vec![Some(Literal::new(0, true)), None, None]);
+	let second_true = Clause::new(vec![None, Some(Literal::new(1, true)), None]);
+	let mut dnf = DNF::new(
+		samples
+			.iter()
+			.map(Clause::from)
+			.chain([first_true.clone(), second_true.clone()])
+			.collect(),
+	);
+	let store = SampleStore::new(3, &samples);
+
+	let removed = dnf.remove_redundant_clauses(&store);
+
+	assert_eq!(3, removed.len());
+	assert!(removed.iter().all(|clause| clause.length() == 3));
+	assert!(dnf.clauses().contains(&first_true));
+	assert!(dnf.clauses().contains(&second_true));
+	assert!(samples.iter().all(|sample| dnf.evaluate(sample) == Ok(true)));
+}
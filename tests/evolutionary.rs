@@ -0,0 +1,158 @@
+use proof::algorithms::local_search::algorithms::evolutionary::{
+	crossover,
+	Population,
+};
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	local_search,
+	AlgorithmRunner,
+	ErrorKind,
+	NeighbourhoodOperator,
+};
+use proof::boolean_formulae::sample_store::SampleStore;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+fn operators() -> Vec<Box<dyn NeighbourhoodOperator>> {
+	vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	})]
+}
+
+/// Returns the state after the given number of hill climbing steps from the initial
+/// state.
+fn climbed(positive_store: &SampleStore, negative_store: &SampleStore, steps: u32) -> State {
+	let algorithm = Algorithm::BasicHillClimber {
+		max_iterations: steps,
+	};
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		common::state(positive_store, negative_store),
+		positive_store,
+		negative_store,
+		operators(),
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	while runner.step() {}
+	runner.current_state().clone()
+}
+
+#[test]
+fn crossover_is_feasible() {
+	let (positive_store, negative_store) = common::stores();
+	let first = common::state(&positive_store, &negative_store);
+	let second = climbed(&positive_store, &negative_store, 5);
+
+	for seed in 0..20 {
+		let child = crossover(
+			&first,
+			&second,
+			&mut StdRng::seed_from_u64(seed),
+			&positive_store,
+			&negative_store,
+		);
+
+		assert!(child.is_feasible(&positive_store, &negative_store));
+		for which_dnf in [true, false] {
+			assert!(child.dnf(which_dnf).clauses().iter().all(|clause| {
+				first.dnf(which_dnf).clauses().contains(clause)
+					|| second.dnf(which_dnf).clauses().contains(clause)
+			}));
+		}
+	}
+}
+
+#[test]
+fn population_keeps_the_best() {
+	let (positive_store, negative_store) = common::stores();
+	let regularizer = Regularizer::DepthAndLength;
+	let states: Vec<State> = [0, 2, 4]
+		.into_iter()
+		.map(|steps| climbed(&positive_store, &negative_store, steps))
+		.collect();
+	let value = |state: &State| regularizer.regularize(state);
+	let mut population = Population::new(states[..2].to_vec(), regularizer);
+
+	assert_eq!(2, population.len());
+	assert_eq!(value(&states[1]), value(population.best()));
+	// The tournament of the whole population selects the best state.
+	assert_eq!(
+		value(&states[1]),
+		value(population.tournament(2, &mut StdRng::seed_from_u64(0)))
+	);
+
+	population.select(vec![states[2].clone(), states[0].clone()], regularizer);
+	assert_eq!(2, population.len());
+	assert_eq!(value(&states[2]), value(population.best()));
+	assert!(value(population.tournament(1, &mut StdRng::seed_from_u64(0))) <= value(&states[1]));
+}
+
+#[test]
+fn best_state_never_gets_worse() {
+	let (positive_store, negative_store) = common::stores();
+	let algorithm = Algorithm::Evolutionary {
+		max_iterations:        10,
+		population_size:       4,
+		tournament_size:       2,
+		crossover_probability: 0.5,
+		mutation_probability:  0.5,
+		mutation_strength:     2,
+	};
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		operators(),
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	let mut previous_value = Regularizer::DepthAndLength.regularize(runner.current_state());
+	while runner.step() {
+		let value = Regularizer::DepthAndLength.regularize(runner.current_state());
+		assert!(value <= previous_value);
+		assert!(runner
+			.current_state()
+			.is_feasible(&positive_store, &negative_store));
+		previous_value = value;
+	}
+
+	assert_eq!(10, runner.iteration());
+}
+
+#[test]
+fn rejects_probabilities_out_of_range() {
+	let evolutionary = |crossover_probability, mutation_probability| Algorithm::Evolutionary {
+		max_iterations: 10,
+		population_size: 4,
+		tournament_size: 2,
+		crossover_probability,
+		mutation_probability,
+		mutation_strength: 1,
+	};
+
+	assert!(evolutionary(0.0, 1.0).is_valid());
+	assert!(!evolutionary(1.5, 0.5).is_valid());
+	assert!(!evolutionary(0.5, -0.5).is_valid());
+	assert!(!evolutionary(f64::NAN, 0.5).is_valid());
+
+	// The config is rejected before the data is read.
+	let mut config = RunConfig::default();
+	config.algorithm = evolutionary(0.5, 2.0);
+	config.data_path = String::from("does/not/exist.json");
+	assert!(matches!(
+		local_search(&config),
+		Err(ErrorKind::InvalidAlgorithm(Algorithm::Evolutionary { .. }))
+	));
+}
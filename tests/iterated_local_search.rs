@@ -0,0 +1,86 @@
+use proof::algorithms::local_search::algorithms::iterated_local_search::{
+	Acceptance,
+	Restarts,
+};
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	AlgorithmRunner,
+	NeighbourhoodOperator,
+};
+
+mod common;
+
+#[test]
+fn acceptance() {
+	assert!(Acceptance::Better.accept(2.0, 1.0));
+	assert!(!Acceptance::Better.accept(2.0, 2.0));
+	assert!(Acceptance::NotWorse.accept(2.0, 2.0));
+	assert!(!Acceptance::NotWorse.accept(2.0, 3.0));
+	assert!(Acceptance::Always.accept(2.0, 3.0));
+}
+
+#[test]
+fn restarts_from_best_local_optimum() {
+	let (positive_store, negative_store) = common::stores();
+	let initial_state = common::state(&positive_store, &negative_store);
+	let mut restarts = Restarts::new(Some(2), &initial_state, 10.0);
+
+	assert!(restarts.record(&initial_state, 11.0).is_none());
+	let best = restarts.record(&initial_state, 12.0).map(Clone::clone);
+	assert!(best.map_or(false, |best| best.positive_eq(initial_state.dnf(true))));
+	// Restarting resets the stagnation, as does finding a better local optimum.
+	assert!(restarts.record(&initial_state, 11.0).is_none());
+	assert!(restarts.record(&initial_state, 9.0).is_none());
+	assert!(restarts.record(&initial_state, 11.0).is_none());
+	assert!(restarts.record(&initial_state, 11.0).is_some());
+
+	let mut without_restarts = Restarts::new(None, &initial_state, 10.0);
+	assert!((0..10).all(|_| without_restarts.record(&initial_state, 11.0).is_none()));
+}
+
+#[test]
+fn never_worse_than_previous_local_optimum() {
+	let (positive_store, negative_store) = common::stores();
+	for restart_after in [None, Some(2)] {
+		let operators: Vec<Box<dyn NeighbourhoodOperator>> =
+			vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+				neighbourhood_limit: None,
+				shuffle:             true,
+			})];
+		let algorithm = Algorithm::IteratedLocalSearch {
+			max_iterations: 10,
+			perturbation: NeighbourhoodGenerator::InsertOneLiteral {
+				neighbourhood_limit: None,
+				shuffle:             true,
+			},
+			perturbation_strength: 2,
+			local_search_iterations: 10,
+			acceptance: Acceptance::Better,
+			restart_after,
+		};
+		let mut runner = AlgorithmRunner::new(
+			algorithm.into_search_algorithm(),
+			common::state(&positive_store, &negative_store),
+			&positive_store,
+			&negative_store,
+			operators,
+			Regularizer::DepthAndLength,
+			TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+			0,
+		);
+		let mut previous_value = Regularizer::DepthAndLength.regularize(runner.current_state());
+		while runner.step() {
+			let value = Regularizer::DepthAndLength.regularize(runner.current_state());
+			assert!(value <= previous_value);
+			assert!(runner
+				.current_state()
+				.is_feasible(&positive_store, &negative_store));
+			previous_value = value;
+		}
+
+		assert_eq!(10, runner.iteration());
+	}
+}
@@ -0,0 +1,76 @@
+use proof::algorithms::local_search::algorithms::late_acceptance_hill_climbing::LateAcceptance;
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	AlgorithmRunner,
+	NeighbourhoodOperator,
+};
+
+mod common;
+
+#[test]
+fn compares_against_late_value() {
+	let mut late_acceptance = LateAcceptance::new(3, 10.0);
+
+	assert!(late_acceptance.accept(0, 12.0, 11.0));
+	assert!(late_acceptance.accept(0, 5.0, 10.0));
+	assert!(!late_acceptance.accept(0, 5.0, 11.0));
+
+	for (iteration, value) in [8.0, 7.0, 6.0].into_iter().enumerate() {
+		late_acceptance.record(iteration as u32, value);
+	}
+	// The value three iterations ago is compared against.
+	assert!(late_acceptance.accept(3, 6.0, 8.0));
+	assert!(!late_acceptance.accept(3, 6.0, 9.0));
+	assert!(late_acceptance.accept(4, 6.0, 7.0));
+	assert!(!late_acceptance.accept(4, 6.0, 8.0));
+}
+
+#[test]
+fn empty_history() {
+	let mut late_acceptance = LateAcceptance::new(0, 10.0);
+	late_acceptance.record(0, 8.0);
+
+	// Compares against the value of the last iteration.
+	assert!(late_acceptance.accept(1, 6.0, 8.0));
+	assert!(!late_acceptance.accept(1, 6.0, 9.0));
+}
+
+#[test]
+fn never_worse_than_initial_state() {
+	let (positive_store, negative_store) = common::stores();
+	let initial_state = common::state(&positive_store, &negative_store);
+	let initial_value = Regularizer::DepthAndLength.regularize(&initial_state);
+	let operators: Vec<Box<dyn NeighbourhoodOperator>> = vec![
+		Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		}),
+		Box::new(NeighbourhoodGenerator::InsertOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		}),
+	];
+	let algorithm = Algorithm::LateAcceptanceHillClimber {
+		max_iterations: 50,
+		history_length: 5,
+	};
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		initial_state,
+		&positive_store,
+		&negative_store,
+		operators,
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	while runner.step() {
+		assert!(Regularizer::DepthAndLength.regularize(runner.current_state()) <= initial_value);
+	}
+
+	assert_eq!(50, runner.iteration());
+	assert!(Regularizer::DepthAndLength.regularize(runner.current_state()) < initial_value);
+}
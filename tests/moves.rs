@@ -0,0 +1,138 @@
+use std::collections::BTreeSet;
+
+use proof::algorithms::local_search::moves::{
+	Attribute,
+	Move,
+};
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::state::Measures;
+use proof::boolean_formulae::clause::Clause;
+
+mod common;
+
+fn attribute(which_dnf: bool, feature_id: usize, parity: bool) -> Attribute {
+	Attribute {
+		which_dnf,
+		feature_id,
+		parity,
+	}
+}
+
+#[test]
+fn attributes() {
+	let (positive_samples, negative_samples) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let positive = |index: usize| Clause::from(&positive_samples[index]);
+
+	assert_eq!(
+		vec![attribute(true, 1, false)],
+		Move::RemoveLiteral {
+			which_dnf:  true,
+			clause:     positive(0),
+			feature_id: 1,
+		}
+		.attributes(&state)
+	);
+	assert_eq!(
+		vec![attribute(false, 2, true)],
+		Move::InsertLiteral {
+			which_dnf:  false,
+			clause:     common::clause(&[(0, false)]),
+			feature_id: 2,
+			parity:     true,
+		}
+		.attributes(&state)
+	);
+	// The third feature appears with both parities in the negative DNF.
+	assert_eq!(
+		vec![attribute(false, 2, true), attribute(false, 2, false)],
+		Move::RemoveFromAllClauses {
+			which_dnf:  false,
+			feature_id: 2,
+		}
+		.attributes(&state)
+	);
+	// The first and the third positive sample only differ in the second feature.
+	assert_eq!(
+		vec![attribute(true, 1, false), attribute(true, 1, true)],
+		Move::MergeClauses {
+			which_dnf: true,
+			first:     positive(0),
+			second:    positive(2),
+		}
+		.attributes(&state)
+	);
+	assert_eq!(
+		vec![
+			attribute(false, 0, false),
+			attribute(false, 1, false),
+			attribute(false, 2, false),
+			attribute(false, 3, true),
+		],
+		Move::RemoveClause {
+			which_dnf: false,
+			clause:    Clause::from(&negative_samples[0]),
+		}
+		.attributes(&state)
+	);
+}
+
+#[test]
+fn remove_from_all_clauses() {
+	let (_, negative_samples) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let modification = Move::RemoveFromAllClauses {
+		which_dnf:  false,
+		feature_id: 3,
+	}
+	.modification(&state);
+
+	assert!(!modification.which_dnf());
+	assert_eq!(
+		negative_samples
+			.iter()
+			.map(Clause::from)
+			.collect::<BTreeSet<Clause>>(),
+		modification.removed().iter().cloned().collect()
+	);
+	// The second and the third negative sample only differ in the removed feature.
+	assert_eq!(2, modification.inserted().len());
+	assert!(modification
+		.inserted()
+		.iter()
+		.all(|clause| clause.length() == 3 && clause.literal_at(3).is_none()));
+}
+
+#[test]
+fn neighbours_do_not_modify_the_state() {
+	let (positive_store, negative_store) = common::stores();
+	let mut state = common::state(&positive_store, &negative_store);
+	let initial = state.clone();
+	let neighbours = common::neighbours(
+		&NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             false,
+		},
+		&state,
+		&positive_store,
+		&negative_store,
+	);
+	let modifications: Vec<_> = neighbours
+		.iter()
+		.map(|neighbour| neighbour.modification(&state))
+		.collect();
+
+	assert_eq!(7 * 4, modifications.len());
+	assert!(state.positive_eq(initial.dnf(true)));
+	assert!(state.negative_eq(initial.dnf(false)));
+
+	let modification = modifications[0].clone();
+	let (which_dnf, removed) = (modification.which_dnf(), modification.removed()[0].clone());
+	state.apply(modification, &positive_store, &negative_store);
+	let length = |(positive, negative): (Measures, Measures)| positive.length + negative.length;
+
+	assert!(!state.dnf(which_dnf).clauses().contains(&removed));
+	assert_eq!(length(initial.measures()) - 1, length(state.measures()));
+}
@@ -0,0 +1,405 @@
+use std::collections::BTreeSet;
+use std::num::NonZeroUsize;
+
+use proof::algorithms::local_search::moves::Move;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::operator_registry::OperatorConfig;
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::{
+	local_search,
+	ErrorKind,
+	NeighbourhoodOperator,
+};
+use proof::boolean_formulae::clause::Clause;
+use proof::boolean_formulae::dnf::DNF;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+#[test]
+fn merge_all_pairs() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let generator = NeighbourhoodGenerator::MergeClauses {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	};
+	let pairs = common::neighbours(&generator, &state, &positive_store, &negative_store)
+		.into_iter()
+		.map(|neighbour| match neighbour {
+			Move::MergeClauses {
+				which_dnf,
+				first,
+				second,
+			} => {
+				assert_ne!(first, second);
+				(which_dnf, first.clone().min(second.clone()), first.max(second))
+			},
+			_ => panic!("MergeClauses must only generate merges."),
+		})
+		.collect::<Vec<(bool, Clause, Clause)>>();
+
+	assert_eq!(6 + 3, pairs.len());
+	assert_eq!(pairs.len(), pairs.iter().collect::<BTreeSet<_>>().len());
+}
+
+#[test]
+fn merge_limit() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let generator = NeighbourhoodGenerator::MergeClauses {
+		neighbourhood_limit: Some(4),
+		shuffle:             false,
+	};
+
+	assert_eq!(
+		4,
+		common::neighbours(&generator, &state, &positive_store, &negative_store).len()
+	);
+}
+
+#[test]
+fn remove_redundant_clause() {
+	let (positive_samples, negative_samples) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let generalized = Clause::from(&positive_samples[0]).generalization(&Clause::from(&positive_samples[1]));
+	let state = State::new(
+		DNF::new(
+			positive_samples
+				.iter()
+				.map(Clause::from)
+				.chain([generalized.clone()])
+				.collect(),
+		),
+		DNF::new(negative_samples.iter().map(Clause::from).collect()),
+		&positive_store,
+		&negative_store,
+	);
+	let removed = common::neighbours(
+		&NeighbourhoodGenerator::RemoveRedundantClause,
+		&state,
+		&positive_store,
+		&negative_store,
+	)
+	.into_iter()
+	.map(|neighbour| match neighbour {
+		Move::RemoveClause { which_dnf, clause } => {
+			assert!(which_dnf);
+			clause
+		},
+		_ => panic!("RemoveRedundantClause must only generate clause removals."),
+	})
+	.collect::<BTreeSet<Clause>>();
+
+	// Each of the samples covered by the generalization is covered twice, so each of the
+	// clauses covering them can be removed on its own.
+	assert!(removed.contains(&generalized));
+	assert!(removed.contains(&Clause::from(&positive_samples[0])));
+	assert!(removed.contains(&Clause::from(&positive_samples[1])));
+	assert!(removed.contains(&Clause::from(&positive_samples[2])));
+	assert!(!removed.contains(&Clause::from(&positive_samples[3])));
+}
+
+#[test]
+fn remove_non_blocking_literals() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let removals = |generator: NeighbourhoodGenerator| {
+		common::neighbours(&generator, &state, &positive_store, &negative_store)
+			.into_iter()
+			.filter(|neighbour| {
+				state.is_feasible_after(&neighbour.modification(&state), &positive_store, &negative_store)
+			})
+			.map(|neighbour| match neighbour {
+				Move::RemoveLiteral {
+					which_dnf,
+					clause,
+					feature_id,
+				} => (which_dnf, clause, feature_id),
+				_ => panic!("Literal removals must only generate literal removals."),
+			})
+			.collect::<Vec<(bool, Clause, usize)>>()
+	};
+	let non_blocking = removals(NeighbourhoodGenerator::RemoveNonBlockingLiteral {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	});
+	let feasible = removals(NeighbourhoodGenerator::RemoveOneLiteral {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	});
+	let generated = common::neighbours(
+		&NeighbourhoodGenerator::RemoveNonBlockingLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		},
+		&state,
+		&positive_store,
+		&negative_store,
+	)
+	.len();
+
+	// All generated neighbours are feasible, and all feasible removals are generated.
+	assert_eq!(generated, non_blocking.len());
+	assert!(!feasible.is_empty());
+	assert_eq!(
+		feasible.into_iter().collect::<BTreeSet<_>>(),
+		non_blocking.into_iter().collect::<BTreeSet<_>>()
+	);
+}
+
+#[test]
+fn ruin_and_recreate() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let generator = NeighbourhoodGenerator::RuinAndRecreate {
+		ruined_clauses:     NonZeroUsize::new(2).unwrap(),
+		candidates:         2,
+		neighbourhood_size: 5,
+	};
+	let neighbours = common::neighbours(&generator, &state, &positive_store, &negative_store);
+
+	assert_eq!(5, neighbours.len());
+	for neighbour in neighbours {
+		match &neighbour {
+			Move::ReplaceClauses {
+				removed, inserted, ..
+			} => {
+				assert_eq!(2, removed.len());
+				assert!(!inserted.is_empty() && inserted.len() <= 2);
+			},
+			_ => panic!("RuinAndRecreate must only generate clause replacements."),
+		}
+		assert!(state.is_feasible_after(&neighbour.modification(&state), &positive_store, &negative_store));
+	}
+}
+
+#[test]
+fn ruin_and_recreate_skips_unchanged() {
+	let (positive_store, negative_store) = common::stores();
+	// The clause is one of the maximal generalizations of each positive sample, so it is
+	// recreated exactly after being ruined for some of the seeds.
+	let state = State::new(
+		DNF::new(vec![common::clause(&[(0, true)])]),
+		DNF::new(Vec::new()),
+		&positive_store,
+		&negative_store,
+	);
+	let generator = NeighbourhoodGenerator::RuinAndRecreate {
+		ruined_clauses:     NonZeroUsize::new(1).unwrap(),
+		candidates:         1,
+		neighbourhood_size: 50,
+	};
+	let neighbours = common::neighbours(&generator, &state, &positive_store, &negative_store);
+
+	assert!(neighbours.len() < 50);
+	for neighbour in neighbours {
+		match neighbour {
+			Move::ReplaceClauses {
+				removed, inserted, ..
+			} => assert_ne!(removed, inserted),
+			_ => panic!("RuinAndRecreate must only generate clause replacements."),
+		}
+	}
+}
+
+#[test]
+fn insert_into_random_clause() {
+	let (positive_store, negative_store) = common::stores();
+	let first = common::clause(&[(0, true)]);
+	let second = common::clause(&[(0, true), (3, true)]);
+	let state = State::new(
+		DNF::new(vec![first.clone(), second.clone()]),
+		DNF::new(Vec::new()),
+		&positive_store,
+		&negative_store,
+	);
+	// The literal of feature 2 is inserted into either clause, depending on the seed.
+	let targets = (0..20)
+		.flat_map(|seed| {
+			NeighbourhoodGenerator::InsertOneRemoveElsewhere
+				.generate_neighbourhood(
+					&state,
+					&positive_store,
+					&negative_store,
+					&mut StdRng::seed_from_u64(seed),
+				)
+				.filter_map(|neighbour| match neighbour {
+					Move::InsertOneRemoveElsewhere {
+						clause, feature_id, ..
+					} => (feature_id == 2).then(|| clause),
+					_ => panic!("InsertOneRemoveElsewhere must only generate its own moves."),
+				})
+				.collect::<Vec<Clause>>()
+		})
+		.collect::<Vec<Clause>>();
+
+	assert!(targets.contains(&first));
+	assert!(targets.contains(&second));
+}
+
+#[test]
+fn ruin_nothing() {
+	let config = r#"{"RuinAndRecreate": {"ruined_clauses": 0, "candidates": 2, "neighbourhood_size": 5}}"#;
+
+	assert!(serde_json::from_str::<NeighbourhoodGenerator>(config).is_err());
+}
+
+#[test]
+fn remove_patches() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	// The samples are images of 2x2 pixels.
+	let patches = |patch_size: usize| {
+		let generator = NeighbourhoodGenerator::RemovePatch {
+			width: 2,
+			height: 2,
+			patch_size,
+			neighbourhood_limit: None,
+			shuffle: true,
+		};
+		common::neighbours(&generator, &state, &positive_store, &negative_store)
+			.into_iter()
+			.map(|neighbour| match neighbour {
+				Move::RemoveLiterals { feature_ids, .. } => feature_ids,
+				_ => panic!("RemovePatch must only generate literal removals."),
+			})
+			.collect::<Vec<Vec<usize>>>()
+	};
+
+	assert_eq!(7 * 4, patches(1).len());
+	assert!(patches(1).iter().all(|feature_ids| feature_ids.len() == 1));
+	assert_eq!(vec![vec![0, 1, 2, 3]; 7], patches(2));
+	assert!(patches(3).is_empty());
+}
+
+#[test]
+fn remove_patches_must_fit_the_data() {
+	let (positive_samples, negative_samples) = common::samples();
+	let data_path = std::env::temp_dir().join("proof-remove-patches-must-fit-the-data.json");
+	std::fs::write(
+		&data_path,
+		serde_json::to_string(&[positive_samples, negative_samples].concat()).unwrap(),
+	)
+	.unwrap();
+	let search = |width: usize, height: usize, patch_size: usize, image_dimensions: Option<(u32, u32)>| {
+		let mut config = RunConfig::default();
+		config.data_path = data_path.to_string_lossy().into_owned();
+		config.image_dimensions = image_dimensions;
+		config.neighbourhood_generators =
+			vec![OperatorConfig::BuiltIn(NeighbourhoodGenerator::RemovePatch {
+				width,
+				height,
+				patch_size,
+				neighbourhood_limit: None,
+				shuffle: true,
+			})];
+		local_search(&config)
+	};
+
+	// The data consists of 4 features, which are no images of 2x3 pixels.
+	assert!(matches!(
+		search(2, 3, 1, None),
+		Err(ErrorKind::WrongPatchDimensions(2, 3, 4))
+	));
+	assert!(matches!(
+		search(4, 1, 1, Some((2, 2))),
+		Err(ErrorKind::ConflictingPatchDimensions(4, 1, 2, 2))
+	));
+	assert!(matches!(
+		search(2, 2, 0, None),
+		Err(ErrorKind::InvalidPatchSize(0, 2, 2))
+	));
+	assert!(matches!(
+		search(4, 1, 2, None),
+		Err(ErrorKind::InvalidPatchSize(2, 4, 1))
+	));
+}
+
+#[test]
+fn remove_one_literal() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let removals = |neighbourhood_limit: Option<usize>, shuffle: bool| {
+		let generator = NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit,
+			shuffle,
+		};
+		common::neighbours(&generator, &state, &positive_store, &negative_store)
+			.into_iter()
+			.map(|neighbour| match neighbour {
+				Move::RemoveLiteral {
+					which_dnf,
+					clause,
+					feature_id,
+				} => {
+					assert!(clause.literal_at(feature_id).is_some());
+					(which_dnf, clause, feature_id)
+				},
+				_ => panic!("RemoveOneLiteral must only generate literal removals."),
+			})
+			.collect::<Vec<(bool, Clause, usize)>>()
+	};
+	let all = removals(None, false).into_iter().collect::<BTreeSet<_>>();
+
+	assert_eq!(7 * 4, all.len());
+	assert_eq!(all, removals(None, true).into_iter().collect());
+	assert_ne!(removals(None, false), removals(None, true));
+	// The same seed generates the same neighbourhood.
+	assert_eq!(removals(None, true), removals(None, true));
+	assert_eq!(removals(Some(10), true), removals(Some(10), true));
+	let limited = removals(Some(10), true);
+	assert_eq!(10, limited.iter().collect::<BTreeSet<_>>().len());
+	assert!(limited.iter().all(|removal| all.contains(removal)));
+	assert_eq!(all, removals(Some(100), false).into_iter().collect());
+}
+
+#[test]
+fn insert_one_literal() {
+	let (_, negative_samples) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let first = common::clause(&[(0, true), (1, false)]);
+	let second = common::clause(&[(0, true), (1, true)]);
+	let state = State::new(
+		DNF::new(vec![first.clone(), second.clone()]),
+		DNF::new(negative_samples.iter().map(Clause::from).collect()),
+		&positive_store,
+		&negative_store,
+	);
+	let insertions = |neighbourhood_limit: Option<usize>| {
+		let generator = NeighbourhoodGenerator::InsertOneLiteral {
+			neighbourhood_limit,
+			shuffle: true,
+		};
+		common::neighbours(&generator, &state, &positive_store, &negative_store)
+	};
+	let all = insertions(None);
+	let feasible = all
+		.iter()
+		.filter(|neighbour| {
+			state.is_feasible_after(&neighbour.modification(&state), &positive_store, &negative_store)
+		})
+		.map(|neighbour| match neighbour {
+			Move::InsertLiteral {
+				which_dnf,
+				clause,
+				feature_id,
+				parity,
+			} => (*which_dnf, clause.clone(), *feature_id, *parity),
+			_ => panic!("InsertOneLiteral must only generate literal insertions."),
+		})
+		.collect::<BTreeSet<_>>();
+
+	// Both literals of both absent features of both clauses, the clauses of the negative
+	// DNF contain all literals.
+	assert_eq!(2 * 2 * 2, all.len());
+	assert_eq!(
+		[(true, first, 3, true), (true, second, 2, false)]
+			.into_iter()
+			.collect::<BTreeSet<_>>(),
+		feasible
+	);
+	assert_eq!(3, insertions(Some(3)).len());
+}
@@ -0,0 +1,125 @@
+use proof::algorithms::local_search::moves::Move;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::operator_registry::{
+	ErrorKind,
+	OperatorConfig,
+};
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::{
+	local_search,
+	NeighbourhoodOperator,
+	OperatorRegistry,
+};
+use proof::boolean_formulae::sample_store::SampleStore;
+use rand::rngs::StdRng;
+use serde::Deserialize;
+
+/// Removes all clauses of one of the `DNF`s.
+#[derive(Debug, Deserialize)]
+struct RemoveAllClauses {
+	which_dnf: bool,
+}
+
+impl NeighbourhoodOperator for RemoveAllClauses {
+	fn generate_neighbourhood<'a>(
+		&self,
+		state: &'a State,
+		_positive_samples: &'a SampleStore,
+		_negative_samples: &'a SampleStore,
+		_rng: &mut StdRng,
+	) -> Box<dyn Iterator<Item = Move> + 'a> {
+		Box::new(std::iter::once(Move::ReplaceClauses {
+			which_dnf: self.which_dnf,
+			removed:   state.dnf(self.which_dnf).clauses().iter().cloned().collect(),
+			inserted:  Vec::new(),
+		}))
+	}
+}
+
+fn registry() -> OperatorRegistry {
+	let mut registry = OperatorRegistry::default();
+	registry.register("RemoveAllClauses", |parameters| {
+		Ok(Box::new(serde_json::from_value::<RemoveAllClauses>(parameters)?))
+	});
+	registry
+}
+
+fn config(json: &str) -> OperatorConfig { serde_json::from_str(json).unwrap() }
+
+#[test]
+fn built_in() {
+	let config = config(r#"{"RemoveOneLiteral": {"neighbourhood_limit": 10, "shuffle": true}}"#);
+
+	assert!(matches!(config, OperatorConfig::BuiltIn(_)));
+	assert!(OperatorRegistry::default().create(&config).is_ok());
+}
+
+#[test]
+fn invalid_built_in() {
+	let error = serde_json::from_str::<OperatorConfig>(
+		r#"{"RemoveOneLiteral": {"neighbourhood_limit": "ten", "shuffle": true}}"#,
+	)
+	.unwrap_err();
+
+	assert!(error.to_string().contains("invalid type"), "{}", error);
+}
+
+#[test]
+fn built_in_names() {
+	for name in NeighbourhoodGenerator::NAMES {
+		let error = serde_json::from_value::<NeighbourhoodGenerator>(serde_json::json!({ name: {} }))
+			.err()
+			.map(|error| error.to_string())
+			.unwrap_or_default();
+
+		assert!(!error.contains("unknown variant"), "{}", error);
+	}
+}
+
+#[test]
+fn registered() {
+	let config = config(r#"{"RemoveAllClauses": {"which_dnf": true}}"#);
+
+	assert!(matches!(config, OperatorConfig::Registered(_)));
+	assert!(registry().create(&config).is_ok());
+	assert!(matches!(
+		OperatorRegistry::default().create(&config),
+		Err(ErrorKind::UnknownOperator(name)) if name == "RemoveAllClauses"
+	));
+}
+
+#[test]
+fn invalid_parameters() {
+	let config = config(r#"{"RemoveAllClauses": {"which": true}}"#);
+
+	assert!(matches!(
+		registry().create(&config),
+		Err(ErrorKind::InvalidParameters(_))
+	));
+}
+
+#[test]
+fn malformed() {
+	let config = config(r#"{"RemoveAllClauses": {"which_dnf": true}, "RemoveOneLiteral": null}"#);
+
+	assert!(matches!(
+		registry().create(&config),
+		Err(ErrorKind::MalformedConfig)
+	));
+}
+
+#[test]
+fn unknown_operator_in_run_config() {
+	let mut config = RunConfig::default();
+	config.neighbourhood_generators = vec![self::config(r#"{"RemoveAllClauses": {"which_dnf": true}}"#)];
+	// The config is rejected before the data is read.
+	config.data_path = String::from("does/not/exist.json");
+
+	assert!(matches!(
+		local_search(&config),
+		Err(proof::algorithms::local_search::ErrorKind::InvalidOperator(
+			ErrorKind::UnknownOperator(_)
+		))
+	));
+}
@@ -0,0 +1,202 @@
+use proof::algorithms::local_search::algorithms::{
+	Algorithm,
+	ErrorKind,
+};
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::operator_registry::OperatorConfig;
+use proof::algorithms::local_search::operator_selection::{
+	OperatorSelection,
+	OperatorSelector,
+};
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::termination_criteria::{
+	TerminationCriteria,
+	TerminationReason,
+};
+use proof::algorithms::local_search::{
+	local_search,
+	AlgorithmRunner,
+	NeighbourhoodOperator,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+#[test]
+fn upper_confidence_bound_tries_all_operators_first() {
+	let mut selector = OperatorSelector::new(OperatorSelection::UpperConfidenceBound { exploration: 0.0 }, 3);
+	let mut rng = StdRng::seed_from_u64(0);
+	let first_selections: Vec<Option<usize>> = (0..3)
+		.map(|index| {
+			let selected = selector.select(&mut rng);
+			selector.reward(index == 1);
+			selected
+		})
+		.collect();
+
+	assert_eq!(vec![Some(0), Some(1), Some(2)], first_selections);
+	// Without exploration, the only successful operator is exploited.
+	assert_eq!(Some(1), selector.select(&mut rng));
+}
+
+#[test]
+fn probability_matching_tracks_statistics() {
+	let mut selector = OperatorSelector::new(
+		OperatorSelection::ProbabilityMatching {
+			adaptation_rate: 0.5,
+			min_probability: 0.1,
+		},
+		2,
+	);
+	let mut rng = StdRng::seed_from_u64(0);
+	for _ in 0..20 {
+		let selected = selector.select(&mut rng);
+		selector.reward(selected == Some(0));
+	}
+	let statistics = selector.statistics().unwrap();
+
+	assert_eq!(
+		20,
+		statistics.iter().map(|operator| operator.selections).sum::<u32>()
+	);
+	assert_eq!(statistics[0].selections, statistics[0].improvements);
+	assert_eq!(0, statistics[1].improvements);
+	assert!(statistics[0].selections > statistics[1].selections);
+}
+
+#[test]
+fn all_operators_without_statistics() {
+	let mut selector = OperatorSelector::new(OperatorSelection::All, 2);
+
+	assert_eq!(None, selector.select(&mut StdRng::seed_from_u64(0)));
+	assert!(selector.statistics().is_none());
+	assert!(selector.exhaust());
+}
+
+#[test]
+fn runner_continues_after_unsuccessful_operator() {
+	let (positive_store, negative_store) = common::stores();
+	// The patches do not fit into the images of 2x2 pixels, so hill climbing only
+	// continues because removing literals improves the state.
+	let operators: Vec<Box<dyn NeighbourhoodOperator>> = vec![
+		Box::new(NeighbourhoodGenerator::RemovePatch {
+			width:               2,
+			height:              2,
+			patch_size:          3,
+			neighbourhood_limit: None,
+			shuffle:             true,
+		}),
+		Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		}),
+	];
+	let mut runner = AlgorithmRunner::new(
+		Algorithm::BasicHillClimber { max_iterations: 100 }.into_search_algorithm(),
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		operators,
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default(),
+		0,
+	)
+	.with_operator_selection(OperatorSelection::UpperConfidenceBound { exploration: 1.0 })
+	.unwrap();
+	while runner.step() {}
+	let statistics = runner.operator_statistics().unwrap();
+
+	assert_eq!(
+		Some(TerminationReason::NoAcceptableNeighbour),
+		runner.termination_reason()
+	);
+	assert_eq!(0, statistics[0].improvements);
+	assert!(statistics[0].selections > 0);
+	assert!(statistics[1].improvements > 0);
+	assert!(runner
+		.current_state()
+		.is_feasible(&positive_store, &negative_store));
+}
+
+#[test]
+fn variable_neighbourhood_search_rejects_selection() {
+	let (positive_store, negative_store) = common::stores();
+	let algorithm = Algorithm::VariableNeighbourhoodSearch { max_iterations: 10 };
+	let runner = |operator_selection: OperatorSelection| {
+		let operators: Vec<Box<dyn NeighbourhoodOperator>> =
+			vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+				neighbourhood_limit: None,
+				shuffle:             true,
+			})];
+		AlgorithmRunner::new(
+			algorithm.into_search_algorithm(),
+			common::state(&positive_store, &negative_store),
+			&positive_store,
+			&negative_store,
+			operators,
+			Regularizer::DepthAndLength,
+			TerminationCriteria::default(),
+			0,
+		)
+		.with_operator_selection(operator_selection)
+	};
+	let selection = OperatorSelection::UpperConfidenceBound { exploration: 1.0 };
+
+	assert!(runner(OperatorSelection::All).is_ok());
+	assert!(matches!(
+		runner(selection),
+		Err(ErrorKind::ConflictingOperatorSelection)
+	));
+	// The config is rejected before the data is read.
+	let mut config = RunConfig::default();
+	config.algorithm = algorithm;
+	config.operator_selection = selection;
+	config.data_path = String::from("does/not/exist.json");
+	assert!(matches!(
+		local_search(&config),
+		Err(proof::algorithms::local_search::ErrorKind::ConflictingOperatorSelection(..))
+	));
+}
+
+#[test]
+fn rejects_parameters_out_of_range() {
+	let probability_matching = |adaptation_rate, min_probability| OperatorSelection::ProbabilityMatching {
+		adaptation_rate,
+		min_probability,
+	};
+
+	assert!(OperatorSelection::UpperConfidenceBound { exploration: 0.0 }.is_valid(2));
+	assert!(!OperatorSelection::UpperConfidenceBound { exploration: -1.0 }.is_valid(2));
+	assert!(!OperatorSelection::UpperConfidenceBound {
+		exploration: f64::NAN,
+	}
+	.is_valid(2));
+	assert!(probability_matching(1.0, 0.5).is_valid(2));
+	assert!(!probability_matching(0.0, 0.1).is_valid(2));
+	assert!(!probability_matching(1.5, 0.1).is_valid(2));
+	assert!(!probability_matching(0.5, 0.6).is_valid(2));
+	assert!(!probability_matching(0.5, -0.1).is_valid(2));
+
+	// The config is rejected before the data is read.
+	let mut config = RunConfig::default();
+	config.operator_selection = probability_matching(0.5, 1.0);
+	config.neighbourhood_generators = [1, 2]
+		.into_iter()
+		.map(|patch_size| {
+			OperatorConfig::BuiltIn(NeighbourhoodGenerator::RemovePatch {
+				width: 2,
+				height: 2,
+				patch_size,
+				neighbourhood_limit: None,
+				shuffle: true,
+			})
+		})
+		.collect();
+	config.data_path = String::from("does/not/exist.json");
+	assert!(matches!(
+		local_search(&config),
+		Err(proof::algorithms::local_search::ErrorKind::InvalidOperatorSelection(_, 2))
+	));
+}
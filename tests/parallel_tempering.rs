@@ -0,0 +1,147 @@
+use proof::algorithms::local_search::algorithms::parallel_tempering::Replicas;
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	local_search,
+	AlgorithmRunner,
+	ErrorKind,
+	NeighbourhoodOperator,
+};
+use proof::boolean_formulae::sample_store::SampleStore;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+fn operators() -> Vec<Box<dyn NeighbourhoodOperator>> {
+	vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	})]
+}
+
+/// Returns a state better than the initial state.
+fn improved(positive_store: &SampleStore, negative_store: &SampleStore) -> State {
+	let algorithm = Algorithm::BasicHillClimber { max_iterations: 3 };
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		common::state(positive_store, negative_store),
+		positive_store,
+		negative_store,
+		operators(),
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	while runner.step() {}
+	runner.current_state().clone()
+}
+
+#[test]
+fn geometric_temperatures() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let temperatures = |count: usize| {
+		Replicas::new(&state, count, 1.0, 4.0)
+			.replicas_mut()
+			.iter()
+			.map(|replica| replica.temperature)
+			.collect::<Vec<f64>>()
+	};
+
+	assert_eq!(vec![1.0, 2.0, 4.0], temperatures(3));
+	assert_eq!(vec![1.0], temperatures(1));
+	assert_eq!(vec![1.0], temperatures(0));
+}
+
+#[test]
+fn swaps_better_states_to_colder_replicas() {
+	let (positive_store, negative_store) = common::stores();
+	let regularizer = Regularizer::DepthAndLength;
+	let initial_state = common::state(&positive_store, &negative_store);
+	let better_state = improved(&positive_store, &negative_store);
+	let value = |state: &State| regularizer.regularize(state);
+	let mut rng = StdRng::seed_from_u64(0);
+
+	// A better state of the hotter replica is always exchanged.
+	let mut replicas = Replicas::new(&initial_state, 2, 1.0, 2.0);
+	replicas.replicas_mut()[1].state = better_state.clone();
+	replicas.swap(regularizer, &mut rng);
+	assert_eq!(value(&better_state), value(&replicas.replicas_mut()[0].state));
+	assert_eq!(value(&initial_state), value(&replicas.replicas_mut()[1].state));
+
+	// A better state of the colder replica is kept if the temperatures differ enough.
+	let mut replicas = Replicas::new(&initial_state, 2, 0.001, 1000.0);
+	replicas.replicas_mut()[0].state = better_state.clone();
+	replicas.swap(regularizer, &mut rng);
+	assert_eq!(value(&better_state), value(&replicas.replicas_mut()[0].state));
+	assert_eq!(value(&better_state), value(replicas.best(regularizer)));
+}
+
+#[test]
+fn reports_best_replica() {
+	let (positive_store, negative_store) = common::stores();
+	let initial_state = common::state(&positive_store, &negative_store);
+	let better_state = improved(&positive_store, &negative_store);
+	let mut replicas = Replicas::new(&initial_state, 3, 1.0, 4.0);
+	replicas.replicas_mut()[2].state = better_state.clone();
+
+	assert!(replicas
+		.best(Regularizer::DepthAndLength)
+		.positive_eq(better_state.dnf(true)));
+
+	let algorithm = Algorithm::ParallelTempering {
+		max_iterations:  10,
+		replica_count:   3,
+		min_temperature: 0.5,
+		max_temperature: 5.0,
+		swap_interval:   2,
+	};
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		initial_state,
+		&positive_store,
+		&negative_store,
+		operators(),
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	while runner.step() {
+		assert!(runner
+			.current_state()
+			.is_feasible(&positive_store, &negative_store));
+	}
+
+	assert_eq!(10, runner.iteration());
+}
+
+#[test]
+fn rejects_invalid_temperatures() {
+	let parallel_tempering = |min_temperature, max_temperature| Algorithm::ParallelTempering {
+		max_iterations: 10,
+		replica_count: 2,
+		min_temperature,
+		max_temperature,
+		swap_interval: 1,
+	};
+
+	assert!(parallel_tempering(0.5, 2.0).is_valid());
+	assert!(!parallel_tempering(0.0, 2.0).is_valid());
+	assert!(!parallel_tempering(-1.0, 2.0).is_valid());
+	assert!(!parallel_tempering(0.5, f64::INFINITY).is_valid());
+	assert!(!parallel_tempering(f64::NAN, 2.0).is_valid());
+
+	// The config is rejected before the data is read.
+	let mut config = RunConfig::default();
+	config.algorithm = parallel_tempering(0.0, 2.0);
+	config.data_path = String::from("does/not/exist.json");
+	assert!(matches!(
+		local_search(&config),
+		Err(ErrorKind::InvalidAlgorithm(Algorithm::ParallelTempering { .. }))
+	));
+}
@@ -0,0 +1,62 @@
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::state::State;
+use proof::boolean_formulae::clause::Clause;
+use proof::boolean_formulae::data::Sample;
+use proof::boolean_formulae::dnf::DNF;
+use proof::boolean_formulae::literal::Literal;
+use proof::boolean_formulae::sample_store::SampleStore;
+
+/// A state whose positive `DNF` has 2 clauses of lengths 3 and 1, and whose negative
+/// `DNF` has 1 clause of length 2.
+fn state() -> State {
+	let positive_samples = vec![
+		Sample::new(true, vec![true, false, true]),
+		Sample::new(true, vec![false, true, true]),
+	];
+	let negative_samples = vec![Sample::new(false, vec![true, true, false])];
+	State::new(
+		DNF::new(vec![
+			Clause::from(&positive_samples[0]),
+			Clause::new(vec![None, None, Some(Literal::new(2, true))]),
+		]),
+		DNF::new(vec![Clause::new(vec![
+			None,
+			Some(Literal::new(1, true)),
+			Some(Literal::new(2, false)),
+		])]),
+		&SampleStore::new(3, &positive_samples),
+		&SampleStore::new(3, &negative_samples),
+	)
+}
+
+#[test]
+fn unit_weights() {
+	let state = state();
+	let weighted = Regularizer::Weighted {
+		length:   1.0,
+		depth:    1.0,
+		clauses:  0.0,
+		positive: 1.0,
+		negative: 1.0,
+	};
+
+	assert!((Regularizer::DepthAndLength.regularize(&state) - 11.0).abs() < f64::EPSILON);
+	assert!(
+		(weighted.regularize(&state) - Regularizer::DepthAndLength.regularize(&state)).abs() < f64::EPSILON
+	);
+}
+
+#[test]
+fn weighted_terms() {
+	let state = state();
+	let weighted = Regularizer::Weighted {
+		length:   0.5,
+		depth:    0.0,
+		clauses:  2.0,
+		positive: 1.0,
+		negative: 0.25,
+	};
+
+	// Positive: 0.5 * 4 + 2 * 2, negative: 0.25 * (0.5 * 2 + 2 * 1).
+	assert!((weighted.regularize(&state) - 6.75).abs() < f64::EPSILON);
+}
@@ -0,0 +1,159 @@
+use proof::algorithms::local_search::algorithms::iterated_local_search::Acceptance;
+use proof::algorithms::local_search::algorithms::simulated_annealing::CoolingSchedule;
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	AlgorithmRunner,
+	Neighbourhood,
+	NeighbourhoodOperator,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+/// Runs the algorithm on the initial state until it terminates and returns the final
+/// state and after how many iterations it terminated.
+fn run(algorithm: Algorithm, seed: u64) -> (State, u32) {
+	let (positive_store, negative_store) = common::stores();
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		})],
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		seed,
+	);
+	while runner.step() {}
+	(runner.current_state().clone(), runner.iteration())
+}
+
+#[test]
+fn same_seed_same_state() {
+	let algorithms = [
+		Algorithm::StochasticHillClimber {
+			max_iterations: 20,
+			selection_prob: 0.5,
+		},
+		Algorithm::SimulatedAnnealing {
+			max_iterations:      20,
+			initial_temperature: 1.0,
+			cooling_schedule:    CoolingSchedule::Logarithmic,
+			reheating:           None,
+		},
+		Algorithm::TabuSearch {
+			max_iterations: 20,
+			tenure:         3,
+			aspiration:     true,
+		},
+		Algorithm::LateAcceptanceHillClimber {
+			max_iterations: 20,
+			history_length: 3,
+		},
+		Algorithm::IteratedLocalSearch {
+			max_iterations:          5,
+			perturbation:            NeighbourhoodGenerator::RemoveOneLiteral {
+				neighbourhood_limit: Some(5),
+				shuffle:             true,
+			},
+			perturbation_strength:   2,
+			local_search_iterations: 3,
+			acceptance:              Acceptance::Always,
+			restart_after:           None,
+		},
+		Algorithm::Evolutionary {
+			max_iterations:        5,
+			population_size:       4,
+			tournament_size:       2,
+			crossover_probability: 0.5,
+			mutation_probability:  0.5,
+			mutation_strength:     2,
+		},
+		Algorithm::ParallelTempering {
+			max_iterations:  10,
+			replica_count:   3,
+			min_temperature: 0.1,
+			max_temperature: 2.0,
+			swap_interval:   2,
+		},
+	];
+
+	for algorithm in algorithms {
+		let (first, first_iterations) = run(algorithm, 42);
+		let (second, second_iterations) = run(algorithm, 42);
+
+		assert_eq!(first_iterations, second_iterations, "{:?}", algorithm);
+		assert!(first.positive_eq(second.dnf(true)), "{:?}", algorithm);
+		assert!(first.negative_eq(second.dnf(false)), "{:?}", algorithm);
+	}
+}
+
+#[test]
+fn random_feasible_neighbour() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let propose = |seed: u64| {
+		let operators: Vec<Box<dyn NeighbourhoodOperator>> = vec![
+			Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+				neighbourhood_limit: None,
+				shuffle:             true,
+			}),
+			Box::new(NeighbourhoodGenerator::InsertOneLiteral {
+				neighbourhood_limit: None,
+				shuffle:             true,
+			}),
+		];
+		let neighbourhood = Neighbourhood::new(operators, &positive_store, &negative_store);
+		let modification = neighbourhood
+			.random_feasible(
+				&state,
+				neighbourhood.operators(),
+				&mut StdRng::seed_from_u64(seed),
+			)
+			.unwrap();
+		(
+			modification.removed().to_vec(),
+			modification.inserted().to_vec(),
+			neighbourhood.feasibility_evaluations(),
+		)
+	};
+
+	for seed in 0..10 {
+		assert_eq!(propose(seed), propose(seed));
+	}
+}
+
+#[test]
+fn random_feasible_neighbour_without_shuffling() {
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let operators: Vec<Box<dyn NeighbourhoodOperator>> =
+		vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             false,
+		})];
+	let neighbourhood = Neighbourhood::new(operators, &positive_store, &negative_store);
+	let proposals = (0..10)
+		.map(|seed| {
+			neighbourhood
+				.random_feasible(
+					&state,
+					neighbourhood.operators(),
+					&mut StdRng::seed_from_u64(seed),
+				)
+				.unwrap()
+				.inserted()
+				.to_vec()
+		})
+		.collect::<Vec<_>>();
+
+	assert!(proposals.iter().any(|proposal| proposal != &proposals[0]));
+}
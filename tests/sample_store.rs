@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+use proof::boolean_formulae::clause::Clause;
+use proof::boolean_formulae::data::{
+	FeatureID,
+	Sample,
+};
+use proof::boolean_formulae::dnf::DNF;
+use proof::boolean_formulae::evaluation::{
+	Cover,
+	Evaluate,
+};
+use proof::boolean_formulae::literal::Literal;
+use proof::boolean_formulae::sample_store::SampleStore;
+
+fn samples() -> Vec<Sample> {
+	vec![
+		Sample::new(true, vec![true, false, false]),
+		Sample::new(false, vec![false, false, false]),
+		Sample::new(true, vec![true, true, false]),
+		Sample::new(false, vec![false, true, true]),
+	]
+}
+
+#[test]
+fn columns() {
+	let store = SampleStore::new(3, &samples());
+
+	assert_eq!(4, store.len());
+	assert_eq!(3, store.dimension());
+	assert_eq!(
+		vec![0, 2],
+		store.samples_with(0, true).iter().collect::<Vec<usize>>()
+	);
+	assert_eq!(
+		vec![1, 3],
+		store.samples_with(0, false).iter().collect::<Vec<usize>>()
+	);
+}
+
+#[test]
+fn coverage_matches_evaluation() {
+	let samples = samples();
+	let store = SampleStore::new(3, &samples);
+
+	let first_positive = Clause::new(vec![Some(Literal::new(0, true)), None, None]);
+	let all_negated = Clause::new(
+		(0..3)
+			.map(|x| Some(Literal::new(x as FeatureID, false)))
+			.collect::<Vec<Option<Literal>>>(),
+	);
+	let dnf = DNF::new(vec![first_positive.clone(), all_negated.clone()]);
+
+	for (index, sample) in samples.iter().enumerate() {
+		assert_eq!(
+			first_positive.evaluate(sample),
+			Ok(first_positive.covered(&store).get(index))
+		);
+		assert_eq!(
+			all_negated.evaluate(sample),
+			Ok(all_negated.covered(&store).get(index))
+		);
+		assert_eq!(dnf.evaluate(sample), Ok(dnf.covered(&store).get(index)));
+	}
+	assert_eq!(vec![0, 1, 2], dnf.covered(&store).iter().collect::<Vec<usize>>());
+}
+
+#[test]
+fn empty_clause_covers_nothing() {
+	let store = SampleStore::new(3, &samples());
+
+	assert!(Clause::empty(3).covered(&store).is_empty());
+}
+
+#[test]
+fn empty_store_keeps_dimension() {
+	let store = SampleStore::new(3, &[]);
+
+	assert!(store.is_empty());
+	assert_eq!(3, store.dimension());
+	assert!(store.samples_with(2, true).is_empty());
+	assert!(Clause::empty(3).covered(&store).is_empty());
+}
@@ -0,0 +1,129 @@
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::termination_criteria::{
+	TerminationCriteria,
+	TerminationReason,
+};
+use proof::algorithms::local_search::{
+	AlgorithmRunner,
+	Neighbourhood,
+	NeighbourhoodOperator,
+	SearchAlgorithm,
+};
+use rand::rngs::StdRng;
+
+mod common;
+
+/// Moves to a random feasible neighbour in each step.
+#[derive(Debug)]
+struct RandomWalk;
+
+impl SearchAlgorithm for RandomWalk {
+	fn step(
+		&mut self,
+		state: &mut State,
+		neighbourhood: &Neighbourhood,
+		_regularizer: Regularizer,
+		rng: &mut StdRng,
+	) -> bool {
+		match neighbourhood.random_feasible(state, neighbourhood.operators(), rng) {
+			Some(modification) => {
+				neighbourhood.apply(state, modification);
+				true
+			},
+			None => false,
+		}
+	}
+}
+
+fn operators() -> Vec<Box<dyn NeighbourhoodOperator>> {
+	vec![Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	})]
+}
+
+fn run(algorithm: Box<dyn SearchAlgorithm>, termination: TerminationCriteria, seed: u64) -> (State, u32) {
+	let (positive_store, negative_store) = common::stores();
+	let mut runner = AlgorithmRunner::new(
+		algorithm,
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		operators(),
+		Regularizer::DepthAndLength,
+		termination,
+		seed,
+	);
+	while runner.step() {}
+	assert!(runner
+		.current_state()
+		.is_feasible(&positive_store, &negative_store));
+	(runner.current_state().clone(), runner.iteration())
+}
+
+#[test]
+fn custom_algorithm() {
+	let termination = TerminationCriteria {
+		max_iterations: Some(3),
+		..TerminationCriteria::default()
+	};
+	let (_, iterations) = run(Box::new(RandomWalk), termination, 0);
+
+	assert!(iterations <= 3);
+}
+
+#[test]
+fn terminates_without_acceptable_neighbour() {
+	let (positive_store, negative_store) = common::stores();
+	let mut runner = AlgorithmRunner::new(
+		Algorithm::BasicHillClimber { max_iterations: 100 }.into_search_algorithm(),
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		operators(),
+		Regularizer::DepthAndLength,
+		TerminationCriteria::default(),
+		0,
+	);
+	while runner.step() {}
+
+	assert_eq!(
+		Some(TerminationReason::NoAcceptableNeighbour),
+		runner.termination_reason()
+	);
+}
+
+#[test]
+fn first_improvement_stops_at_first_improving_neighbour() {
+	let (positive_store, negative_store) = common::stores();
+	let first_step = |algorithm: Algorithm| {
+		let mut runner = AlgorithmRunner::new(
+			algorithm.into_search_algorithm(),
+			common::state(&positive_store, &negative_store),
+			&positive_store,
+			&negative_store,
+			operators(),
+			Regularizer::DepthAndLength,
+			TerminationCriteria::default(),
+			0,
+		);
+		assert!(runner.step());
+		(
+			runner.feasibility_evaluations(),
+			Regularizer::DepthAndLength.regularize(runner.current_state()),
+		)
+	};
+	let initial_value =
+		Regularizer::DepthAndLength.regularize(&common::state(&positive_store, &negative_store));
+	let (best_evaluations, best_value) = first_step(Algorithm::BasicHillClimber { max_iterations: 1 });
+	let (first_evaluations, first_value) =
+		first_step(Algorithm::FirstImprovementHillClimber { max_iterations: 1 });
+
+	assert_eq!(7 * 4, best_evaluations);
+	assert!(first_evaluations < best_evaluations);
+	assert!(best_value < initial_value);
+	assert!(first_value < initial_value);
+}
@@ -0,0 +1,139 @@
+use proof::algorithms::local_search::algorithms::simulated_annealing::{
+	Annealer,
+	CoolingSchedule,
+	Reheating,
+};
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::run_config::RunConfig;
+use proof::algorithms::local_search::{
+	local_search,
+	ErrorKind,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Returns the temperatures after each of the given number of iterations which all
+/// improve the regularizer value.
+fn temperatures(cooling_schedule: CoolingSchedule, accepted: bool, iterations: u32) -> Vec<f64> {
+	let mut annealer = Annealer::new(10.0, cooling_schedule, None);
+	(0..iterations)
+		.map(|iteration| {
+			annealer.cool(accepted, f64::from(100 - iteration));
+			annealer.temperature()
+		})
+		.collect()
+}
+
+fn assert_close(expected: &[f64], actual: &[f64]) {
+	assert_eq!(expected.len(), actual.len());
+	for (expected, actual) in expected.iter().zip(actual) {
+		assert!((expected - actual).abs() < 1e-9, "{} != {}", expected, actual);
+	}
+}
+
+#[test]
+fn cooling_schedules() {
+	assert_close(
+		&[5.0, 2.5, 1.25],
+		&temperatures(CoolingSchedule::Geometric { factor: 0.5 }, true, 3),
+	);
+	assert_close(
+		&[6.0, 2.0, 0.0, 0.0],
+		&temperatures(CoolingSchedule::Linear { decrement: 4.0 }, true, 4),
+	);
+	assert_close(
+		&[10.0 * 2_f64.ln() / 3_f64.ln(), 10.0 * 2_f64.ln() / 4_f64.ln()],
+		&temperatures(CoolingSchedule::Logarithmic, true, 2),
+	);
+}
+
+#[test]
+fn adaptive_cooling() {
+	let adaptive = CoolingSchedule::Adaptive {
+		factor:                 0.5,
+		target_acceptance_rate: 0.5,
+		window:                 2,
+	};
+
+	// The temperature only changes at the end of each window.
+	assert_close(&[10.0, 5.0, 5.0, 2.5], &temperatures(adaptive, true, 4));
+	assert_close(&[10.0, 20.0, 20.0, 40.0], &temperatures(adaptive, false, 4));
+}
+
+#[test]
+fn reheating() {
+	let mut annealer = Annealer::new(
+		10.0,
+		CoolingSchedule::Geometric { factor: 0.5 },
+		Some(Reheating {
+			stagnation:  2,
+			temperature: 4.0,
+		}),
+	);
+	let mut temperatures = Vec::new();
+	for value in [3.0, 2.0, 2.0, 2.0, 2.0, 1.0] {
+		annealer.cool(false, value);
+		temperatures.push(annealer.temperature());
+	}
+
+	// Reheats after two iterations without improvement and cools down from there.
+	assert_close(&[5.0, 2.5, 1.25, 4.0, 2.0, 1.0], &temperatures);
+}
+
+#[test]
+fn metropolis_criterion() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let annealer = Annealer::new(1.0, CoolingSchedule::Logarithmic, None);
+	let mut frozen = Annealer::new(1.0, CoolingSchedule::Linear { decrement: 1.0 }, None);
+	frozen.cool(true, 0.0);
+
+	assert!((0..100).all(|_| annealer.accept(-1.0, &mut rng)));
+	assert!((0..100).all(|_| frozen.accept(0.0, &mut rng)));
+	assert!((0..100).all(|_| !frozen.accept(1.0, &mut rng)));
+	// Deteriorations are accepted with probability exp(-1).
+	let accepted = (0..10_000).filter(|_| annealer.accept(1.0, &mut rng)).count();
+	assert!((3400..4000).contains(&accepted), "{}", accepted);
+}
+
+#[test]
+fn rejects_invalid_parameters() {
+	let simulated_annealing =
+		|initial_temperature, cooling_schedule, reheating| Algorithm::SimulatedAnnealing {
+			max_iterations: 10,
+			initial_temperature,
+			cooling_schedule,
+			reheating,
+		};
+	let adaptive = |factor, target_acceptance_rate| CoolingSchedule::Adaptive {
+		factor,
+		target_acceptance_rate,
+		window: 5,
+	};
+	let reheating = |stagnation, temperature| {
+		Some(Reheating {
+			stagnation,
+			temperature,
+		})
+	};
+
+	assert!(simulated_annealing(1.0, adaptive(0.5, 0.5), reheating(2, 1.0)).is_valid());
+	assert!(!simulated_annealing(f64::INFINITY, CoolingSchedule::Logarithmic, None).is_valid());
+	assert!(!simulated_annealing(-1.0, CoolingSchedule::Logarithmic, None).is_valid());
+	assert!(!simulated_annealing(1.0, CoolingSchedule::Geometric { factor: 1.5 }, None).is_valid());
+	assert!(!simulated_annealing(1.0, CoolingSchedule::Linear { decrement: f64::NAN }, None).is_valid());
+	assert!(!simulated_annealing(1.0, adaptive(0.0, 0.5), None).is_valid());
+	assert!(!simulated_annealing(1.0, adaptive(f64::NAN, 0.5), None).is_valid());
+	assert!(!simulated_annealing(1.0, adaptive(0.5, 1.5), None).is_valid());
+	// Reheating after no stagnating iterations would reheat in every iteration.
+	assert!(!simulated_annealing(1.0, CoolingSchedule::Logarithmic, reheating(0, 1.0)).is_valid());
+	assert!(!simulated_annealing(1.0, CoolingSchedule::Logarithmic, reheating(2, f64::NAN)).is_valid());
+
+	// The config is rejected before the data is read.
+	let mut config = RunConfig::default();
+	config.algorithm = simulated_annealing(1.0, adaptive(-0.5, 0.5), None);
+	config.data_path = String::from("does/not/exist.json");
+	assert!(matches!(
+		local_search(&config),
+		Err(ErrorKind::InvalidAlgorithm(Algorithm::SimulatedAnnealing { .. }))
+	));
+}
@@ -0,0 +1,274 @@
+use proof::algorithms::local_search::moves::Move;
+use proof::algorithms::local_search::state::State;
+use proof::boolean_formulae::clause::Clause;
+use proof::boolean_formulae::dnf::DNF;
+use proof::boolean_formulae::sample_store::SampleStore;
+
+mod common;
+
+/// Checks the incremental evaluation of the move against a state evaluated from scratch,
+/// and returns whether the move is feasible together with the state after applying it.
+fn check(
+	state: &State,
+	neighbour: &Move,
+	positive_store: &SampleStore,
+	negative_store: &SampleStore,
+) -> (bool, State) {
+	let modification = neighbour.modification(state);
+	let mut applied = state.clone();
+	applied.apply(modification.clone(), positive_store, negative_store);
+	let recomputed = State::new(
+		applied.dnf(true).clone(),
+		applied.dnf(false).clone(),
+		positive_store,
+		negative_store,
+	);
+	let feasible = recomputed.is_feasible(positive_store, negative_store);
+
+	assert_eq!(
+		feasible,
+		state.is_feasible_after(&modification, positive_store, negative_store)
+	);
+	assert_eq!(feasible, applied.is_feasible(positive_store, negative_store));
+	assert_eq!(recomputed.measures(), state.measures_after(&modification));
+	assert_eq!(recomputed.measures(), applied.measures());
+	for which_dnf in [true, false] {
+		assert_eq!(
+			recomputed.redundant_clauses(which_dnf),
+			applied.redundant_clauses(which_dnf)
+		);
+	}
+	(feasible, applied)
+}
+
+#[test]
+fn every_move_kind() {
+	let (positive_samples, negative_samples) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let positive = |index: usize| Clause::from(&positive_samples[index]);
+	let negative = |index: usize| Clause::from(&negative_samples[index]);
+	let x0_x1 = common::clause(&[(0, true), (1, true)]);
+	// Each move is applied to the state after the last feasible move.
+	let moves = [
+		(
+			true,
+			Move::RemoveLiteral {
+				which_dnf:  true,
+				clause:     positive(0),
+				feature_id: 3,
+			},
+		),
+		// Covers the second and the third positive sample.
+		(
+			true,
+			Move::RemoveLiterals {
+				which_dnf:   true,
+				clause:      positive(1),
+				feature_ids: vec![2, 3],
+			},
+		),
+		(
+			true,
+			Move::RemoveClause {
+				which_dnf: true,
+				clause:    positive(2),
+			},
+		),
+		(
+			false,
+			Move::RemoveClause {
+				which_dnf: true,
+				clause:    positive(3),
+			},
+		),
+		(
+			false,
+			Move::InsertLiteral {
+				which_dnf:  true,
+				clause:     x0_x1.clone(),
+				feature_id: 3,
+				parity:     true,
+			},
+		),
+		(
+			true,
+			Move::InsertLiteral {
+				which_dnf:  true,
+				clause:     x0_x1,
+				feature_id: 2,
+				parity:     false,
+			},
+		),
+		(
+			false,
+			Move::RemoveFromAllClauses {
+				which_dnf:  false,
+				feature_id: 0,
+			},
+		),
+		(
+			true,
+			Move::RemoveFromAllClauses {
+				which_dnf:  true,
+				feature_id: 2,
+			},
+		),
+		// Removes its own clause twice, and inserts a clause which is present already.
+		(
+			true,
+			Move::InsertOneRemoveElsewhere {
+				which_dnf:  true,
+				clause:     common::clause(&[(0, true), (1, true)]),
+				feature_id: 1,
+				parity:     false,
+			},
+		),
+		(
+			true,
+			Move::MergeClauses {
+				which_dnf: false,
+				first:     negative(1),
+				second:    negative(2),
+			},
+		),
+		(
+			true,
+			Move::ReplaceClauses {
+				which_dnf: false,
+				removed:   vec![negative(0), negative(0)],
+				inserted:  vec![
+					common::clause(&[(0, false)]),
+					common::clause(&[(0, false)]),
+					Clause::empty(4),
+				],
+			},
+		),
+		(
+			false,
+			Move::ReplaceClauses {
+				which_dnf: true,
+				removed:   vec![common::clause(&[(0, true)])],
+				inserted:  vec![common::clause(&[(3, true)])],
+			},
+		),
+	];
+	let mut state = common::state(&positive_store, &negative_store);
+	for (expected, neighbour) in &moves {
+		let (feasible, applied) = check(&state, neighbour, &positive_store, &negative_store);
+
+		assert_eq!(*expected, feasible, "{:?}", neighbour);
+		if feasible {
+			state = applied;
+		}
+	}
+
+	assert!(state.positive_eq(&DNF::new(vec![
+		common::clause(&[(0, true)]),
+		common::clause(&[(0, true), (1, false)]),
+		common::clause(&[(0, true), (3, true)]),
+	])));
+	assert!(state.negative_eq(&DNF::new(vec![
+		common::clause(&[(0, false)]),
+		common::clause(&[(0, false), (1, true), (2, true)]),
+	])));
+}
+
+#[test]
+fn duplicate_and_empty_clauses() {
+	let (positive_samples, _) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let state = common::state(&positive_store, &negative_store);
+	let present = Clause::from(&positive_samples[0]);
+	let generalized = common::clause(&[(0, true), (1, false)]);
+	let neighbour = Move::ReplaceClauses {
+		which_dnf: true,
+		removed:   vec![present.clone(), present.clone()],
+		inserted:  vec![
+			generalized.clone(),
+			generalized.clone(),
+			Clause::from(&positive_samples[1]),
+			Clause::empty(4),
+		],
+	};
+	let modification = neighbour.modification(&state);
+
+	assert_eq!(vec![present], modification.removed());
+	assert_eq!(vec![generalized], modification.inserted());
+	assert!(check(&state, &neighbour, &positive_store, &negative_store).0);
+
+	// Inserting only the empty clause leaves the state unchanged.
+	let neighbour = Move::ReplaceClauses {
+		which_dnf: false,
+		removed:   Vec::new(),
+		inserted:  vec![Clause::empty(4)],
+	};
+	let (feasible, applied) = check(&state, &neighbour, &positive_store, &negative_store);
+
+	assert!(feasible);
+	assert!(neighbour.modification(&state).inserted().is_empty());
+	assert_eq!(state.measures(), applied.measures());
+	assert!(applied.negative_eq(state.dnf(false)));
+}
+
+#[test]
+fn multiply_covered_samples() {
+	let (positive_samples, negative_samples) = common::samples();
+	let (positive_store, negative_store) = common::stores();
+	let positive = |index: usize| Clause::from(&positive_samples[index]);
+	// Covers the first and the third positive sample a second time.
+	let generalized = positive(0).generalization(&positive(2));
+	let state = State::new(
+		DNF::new(
+			positive_samples
+				.iter()
+				.map(Clause::from)
+				.chain([generalized.clone()])
+				.collect(),
+		),
+		DNF::new(negative_samples.iter().map(Clause::from).collect()),
+		&positive_store,
+		&negative_store,
+	);
+	let remove = |removed: Vec<Clause>| Move::ReplaceClauses {
+		which_dnf: true,
+		removed,
+		inserted: Vec::new(),
+	};
+	let feasible = |neighbour: Move| check(&state, &neighbour, &positive_store, &negative_store).0;
+
+	assert!(feasible(remove(vec![positive(0)])));
+	assert!(feasible(remove(vec![positive(2)])));
+	assert!(feasible(remove(vec![generalized.clone()])));
+	assert!(feasible(remove(vec![positive(0), positive(2)])));
+	assert!(!feasible(remove(vec![positive(0), generalized.clone()])));
+	assert!(!feasible(remove(vec![positive(1)])));
+	assert!(!feasible(remove(vec![positive(0), positive(1)])));
+	// Replacing the clauses by a more general one.
+	assert!(feasible(Move::ReplaceClauses {
+		which_dnf: true,
+		removed:   vec![generalized, positive(0), positive(2)],
+		inserted:  vec![common::clause(&[(0, true), (2, false)])],
+	}));
+}
+
+#[test]
+fn single_class() {
+	let (positive_samples, _) = common::samples();
+	let positive_store = SampleStore::new(4, &positive_samples);
+	let negative_store = SampleStore::new(4, &[]);
+	let state = State::new(
+		DNF::new(positive_samples.iter().map(Clause::from).collect()),
+		DNF::new(Vec::new()),
+		&positive_store,
+		&negative_store,
+	);
+
+	assert!(state.is_feasible(&positive_store, &negative_store));
+	// Without negative samples, every literal can be removed.
+	let neighbour = Move::RemoveLiteral {
+		which_dnf:  true,
+		clause:     Clause::from(&positive_samples[0]),
+		feature_id: 0,
+	};
+	assert!(check(&state, &neighbour, &positive_store, &negative_store).0);
+}
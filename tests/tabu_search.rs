@@ -0,0 +1,114 @@
+use proof::algorithms::local_search::algorithms::tabu_search::TabuList;
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::moves::Attribute;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::state::State;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	AlgorithmRunner,
+	NeighbourhoodOperator,
+};
+use proof::boolean_formulae::dnf::DNF;
+
+mod common;
+
+fn attribute(feature_id: usize) -> Attribute {
+	Attribute {
+		which_dnf: true,
+		feature_id,
+		parity: true,
+	}
+}
+
+fn operators() -> Vec<Box<dyn NeighbourhoodOperator>> {
+	vec![
+		Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		}),
+		Box::new(NeighbourhoodGenerator::InsertOneLiteral {
+			neighbourhood_limit: None,
+			shuffle:             true,
+		}),
+	]
+}
+
+#[test]
+fn tenure() {
+	let mut tabu_list = TabuList::new(2, 10.0);
+	tabu_list.record(vec![attribute(0)], 0, 12.0);
+
+	assert!(tabu_list.is_tabu(&[attribute(0)], 1));
+	assert!(tabu_list.is_tabu(&[attribute(1), attribute(0)], 2));
+	assert!(!tabu_list.is_tabu(&[attribute(0)], 3));
+	assert!(!tabu_list.is_tabu(&[attribute(1)], 1));
+	// Touching the attribute again extends its tenure.
+	tabu_list.record(vec![attribute(0)], 2, 12.0);
+	assert!(tabu_list.is_tabu(&[attribute(0)], 4));
+}
+
+#[test]
+fn aspiration() {
+	let mut tabu_list = TabuList::new(2, 10.0);
+
+	assert!(tabu_list.aspires(9.0));
+	assert!(!tabu_list.aspires(10.0));
+	tabu_list.record(vec![attribute(0)], 0, 8.0);
+	assert!(!tabu_list.aspires(9.0));
+	assert!(tabu_list.aspires(7.0));
+	// Worse values do not change the best value found so far.
+	tabu_list.record(vec![attribute(1)], 1, 11.0);
+	assert!(tabu_list.aspires(7.0));
+	assert!(!tabu_list.aspires(8.0));
+}
+
+#[test]
+fn leaves_local_optimum_without_returning() {
+	let (positive_store, negative_store) = common::stores();
+	let regularizer = Regularizer::DepthAndLength;
+	// No literal can be removed without covering a negative sample, but literals can be
+	// inserted into the second clause, as the first one covers all positive samples.
+	let local_optimum = State::new(
+		DNF::new(vec![
+			common::clause(&[(0, true)]),
+			common::clause(&[(1, true), (2, false)]),
+		]),
+		DNF::new(vec![common::clause(&[(0, false)])]),
+		&positive_store,
+		&negative_store,
+	);
+
+	for aspiration in [false, true] {
+		let algorithm = Algorithm::TabuSearch {
+			max_iterations: 6,
+			tenure: 10,
+			aspiration,
+		};
+		let mut tabu_search = AlgorithmRunner::new(
+			algorithm.into_search_algorithm(),
+			local_optimum.clone(),
+			&positive_store,
+			&negative_store,
+			operators(),
+			regularizer,
+			TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+			0,
+		);
+
+		// Moves to the best neighbour although it does not improve the local optimum …
+		assert!(tabu_search.step());
+		let state = tabu_search.current_state();
+		assert!(regularizer.regularize(state) > regularizer.regularize(&local_optimum));
+		assert!(!state.positive_eq(local_optimum.dnf(true)));
+		// … and does not return to it, as undoing the move is tabu and does not improve
+		// the best state found so far.
+		while tabu_search.step() {
+			let state = tabu_search.current_state();
+			assert!(
+				!state.positive_eq(local_optimum.dnf(true)) || !state.negative_eq(local_optimum.dnf(false))
+			);
+			assert!(state.is_feasible(&positive_store, &negative_store));
+		}
+	}
+}
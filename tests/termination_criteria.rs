@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::termination_criteria::{
+	TerminationCriteria,
+	TerminationReason,
+};
+use proof::algorithms::local_search::AlgorithmRunner;
+
+mod common;
+
+/// Runs the algorithm on the initial state until it terminates and returns why and after
+/// how many iterations.
+fn run(
+	algorithm: Algorithm,
+	generator: NeighbourhoodGenerator,
+	termination: TerminationCriteria,
+) -> (Option<TerminationReason>, u32) {
+	let (positive_store, negative_store) = common::stores();
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		vec![Box::new(generator)],
+		Regularizer::DepthAndLength,
+		termination.with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	while runner.step() {}
+	(runner.termination_reason(), runner.iteration())
+}
+
+const REMOVE_ONE_LITERAL: NeighbourhoodGenerator = NeighbourhoodGenerator::RemoveOneLiteral {
+	neighbourhood_limit: None,
+	shuffle:             true,
+};
+
+/// The neighbourhood is truncated to nothing, so no neighbours are generated.
+const NO_NEIGHBOURS: NeighbourhoodGenerator = NeighbourhoodGenerator::RemoveOneLiteral {
+	neighbourhood_limit: Some(0),
+	shuffle:             true,
+};
+
+#[test]
+fn check_criteria() {
+	let criteria = TerminationCriteria {
+		max_iterations:              Some(10),
+		time_limit:                  Some(1.0),
+		max_feasibility_evaluations: Some(100),
+		stagnation:                  Some(5),
+		target_value:                Some(2.0),
+	};
+	let check = |iterations, seconds, evaluations, stagnating_iterations, best_value| {
+		criteria.check(
+			iterations,
+			Duration::from_secs_f64(seconds),
+			evaluations,
+			stagnating_iterations,
+			best_value,
+		)
+	};
+
+	assert_eq!(None, check(9, 0.5, 99, 4, 3.0));
+	assert_eq!(Some(TerminationReason::MaxIterations), check(10, 0.5, 99, 4, 3.0));
+	assert_eq!(Some(TerminationReason::TimeLimit), check(9, 1.0, 99, 4, 3.0));
+	assert_eq!(
+		Some(TerminationReason::MaxFeasibilityEvaluations),
+		check(9, 0.5, 100, 4, 3.0)
+	);
+	assert_eq!(Some(TerminationReason::Stagnation), check(9, 0.5, 99, 5, 3.0));
+	assert_eq!(Some(TerminationReason::TargetValue), check(9, 0.5, 99, 4, 2.0));
+	assert_eq!(
+		None,
+		TerminationCriteria::default().check(u32::MAX, Duration::MAX, u64::MAX, u32::MAX, 0.0)
+	);
+}
+
+#[test]
+fn with_max_iterations() {
+	let limit = |max_iterations: Option<u32>| {
+		TerminationCriteria {
+			max_iterations,
+			..TerminationCriteria::default()
+		}
+		.with_max_iterations(10)
+		.max_iterations
+	};
+
+	assert_eq!(Some(10), limit(None));
+	assert_eq!(Some(5), limit(Some(5)));
+	assert_eq!(Some(10), limit(Some(20)));
+}
+
+#[test]
+fn runner_records_reason() {
+	let basic = Algorithm::BasicHillClimber { max_iterations: 100 };
+
+	assert_eq!(
+		(Some(TerminationReason::MaxIterations), 2),
+		run(
+			Algorithm::BasicHillClimber { max_iterations: 2 },
+			REMOVE_ONE_LITERAL,
+			TerminationCriteria::default()
+		)
+	);
+	assert_eq!(
+		(Some(TerminationReason::MaxFeasibilityEvaluations), 1),
+		run(
+			basic,
+			REMOVE_ONE_LITERAL,
+			TerminationCriteria {
+				max_feasibility_evaluations: Some(1),
+				..TerminationCriteria::default()
+			}
+		)
+	);
+	assert_eq!(
+		Some(TerminationReason::TargetValue),
+		run(
+			basic,
+			REMOVE_ONE_LITERAL,
+			TerminationCriteria {
+				target_value: Some(1000.0),
+				..TerminationCriteria::default()
+			}
+		)
+		.0
+	);
+	assert_eq!(
+		(Some(TerminationReason::NoAcceptableNeighbour), 0),
+		run(basic, NO_NEIGHBOURS, TerminationCriteria::default())
+	);
+}
+
+#[test]
+fn stagnation() {
+	// The stochastic hill climber continues without any neighbour.
+	let stochastic = Algorithm::StochasticHillClimber {
+		max_iterations: 10,
+		selection_prob: 1.0,
+	};
+
+	assert_eq!(
+		(Some(TerminationReason::Stagnation), 3),
+		run(
+			stochastic,
+			NO_NEIGHBOURS,
+			TerminationCriteria {
+				stagnation: Some(3),
+				..TerminationCriteria::default()
+			}
+		)
+	);
+	assert_eq!(
+		(Some(TerminationReason::MaxIterations), 10),
+		run(stochastic, NO_NEIGHBOURS, TerminationCriteria::default())
+	);
+}
@@ -0,0 +1,74 @@
+use proof::algorithms::local_search::algorithms::Algorithm;
+use proof::algorithms::local_search::neighbourhood_generator::NeighbourhoodGenerator;
+use proof::algorithms::local_search::regularizer::Regularizer;
+use proof::algorithms::local_search::termination_criteria::TerminationCriteria;
+use proof::algorithms::local_search::{
+	AlgorithmRunner,
+	NeighbourhoodOperator,
+};
+
+mod common;
+
+#[test]
+fn escalates_and_returns_to_first_neighbourhood() {
+	let (positive_store, negative_store) = common::stores();
+	let regularizer = Regularizer::DepthAndLength;
+	let remove_one_literal = NeighbourhoodGenerator::RemoveOneLiteral {
+		neighbourhood_limit: None,
+		shuffle:             true,
+	};
+	// The first neighbourhood is truncated to nothing, so it never contains an improving
+	// neighbour.
+	let operators: Vec<Box<dyn NeighbourhoodOperator>> = vec![
+		Box::new(NeighbourhoodGenerator::RemoveOneLiteral {
+			neighbourhood_limit: Some(0),
+			shuffle:             true,
+		}),
+		Box::new(remove_one_literal),
+	];
+	let algorithm = Algorithm::VariableNeighbourhoodSearch { max_iterations: 1000 };
+	let mut runner = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		common::state(&positive_store, &negative_store),
+		&positive_store,
+		&negative_store,
+		operators,
+		regularizer,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	let mut improvements = 0;
+
+	loop {
+		// Escalates from the first neighbourhood without moving …
+		let value = regularizer.regularize(runner.current_state());
+		assert!(runner.step());
+		assert_eq!(value, regularizer.regularize(runner.current_state()));
+		// … and returns to it after improving the state in the second one.
+		let continued = runner.step();
+		if regularizer.regularize(runner.current_state()) < value {
+			assert!(continued);
+			improvements += 1;
+		} else {
+			break;
+		}
+	}
+
+	// Terminates after no neighbourhood improves the state.
+	assert!(improvements > 0);
+	assert!(!runner.step());
+	let local_optimum = runner.current_state().clone();
+	assert!(local_optimum.is_feasible(&positive_store, &negative_store));
+	let algorithm = Algorithm::BasicHillClimber { max_iterations: 1 };
+	let mut hill_climber = AlgorithmRunner::new(
+		algorithm.into_search_algorithm(),
+		local_optimum,
+		&positive_store,
+		&negative_store,
+		vec![Box::new(remove_one_literal)],
+		regularizer,
+		TerminationCriteria::default().with_max_iterations(algorithm.max_iterations()),
+		0,
+	);
+	assert!(!hill_climber.step());
+}